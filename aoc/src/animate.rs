@@ -0,0 +1,16 @@
+// Looks up the animation for a day that steps through a simulation
+use color_eyre::{eyre::eyre, Result};
+use common::animate::Animator;
+
+pub fn animator(day: u8) -> Result<Animator> {
+    let animator: Animator = match day {
+        9 => day09::animate::animation,
+        14 => day14::animate::animation,
+        17 => day17::animate::animation,
+        23 => day23::animate::animation,
+        24 => day24::animate::animation,
+        _ => return Err(eyre!("no animation for day {day}")),
+    };
+
+    Ok(animator)
+}
@@ -0,0 +1,266 @@
+// Checks each day's solver against the answers recorded in dayNN/answers.toml:
+//
+//   [input]
+//   part1 = "72478"
+//   part2 = "210367"
+//
+//   [example]
+//   part1 = "24000"
+//   part2 = "45000"
+//
+// The [input] section is checked against data/input.txt and the [example] section against
+// data/example.txt.  A missing section or part is skipped.
+use crate::{day_dir, solver::solver};
+use color_eyre::{eyre::eyre, Result};
+use serde::Deserialize;
+use std::{fmt::Display, fs};
+
+#[derive(Debug, Default, Deserialize)]
+struct Expected {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+impl Expected {
+    fn parts(&self) -> [Option<&str>; 2] {
+        [self.part1.as_deref(), self.part2.as_deref()]
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Answers {
+    input: Option<Expected>,
+    example: Option<Expected>,
+}
+
+impl Answers {
+    fn load(day: u8) -> Result<Self> {
+        let path = day_dir(day).join("answers.toml");
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let s = fs::read_to_string(&path)?;
+        toml::from_str(&s).map_err(|err| eyre!("failed to parse {}: {err}", path.display()))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Status {
+    Ok,
+    Mismatch,
+    Error(String),
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ok => write!(f, "ok"),
+            Self::Mismatch => write!(f, "MISMATCH"),
+            Self::Error(err) => write!(f, "ERROR: {err}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Row {
+    day: u8,
+    data: &'static str,
+    part: u8,
+    expected: String,
+    actual: String,
+    status: Status,
+}
+
+// Answers are compared line by line, ignoring trailing whitespace, so that multi-line answers
+// like the day 10 CRT can be recorded in TOML without worrying about trailing spaces.
+fn same_answer(expected: &str, actual: &str) -> bool {
+    let lines = |s: &str| {
+        s.trim_end()
+            .trim_start_matches('\n')
+            .lines()
+            .map(str::trim_end)
+            .map(str::to_owned)
+            .collect::<Vec<_>>()
+    };
+    lines(expected) == lines(actual)
+}
+
+// Multi-line answers don't fit in a table cell, so they are summarized
+fn cell(answer: &str) -> String {
+    let answer = answer.trim_matches('\n');
+    match answer.lines().count() {
+        0 | 1 => answer.to_owned(),
+        n => format!("({n} lines)"),
+    }
+}
+
+fn check_data(day: u8, data: &'static str, expected: &Expected) -> Result<Vec<Row>> {
+    let path = day_dir(day).join("data").join(format!("{data}.txt"));
+    let input = common::input::read_file(&path)?;
+    check_input(day, data, &input, expected)
+}
+
+fn check_input(day: u8, data: &'static str, input: &str, expected: &Expected) -> Result<Vec<Row>> {
+    let actual = match solver(day)?(input) {
+        Ok(parsed) => parsed
+            .solve_both()
+            .parts
+            .map(|part| part.map(|part| part.answer)),
+        Err(err) => [Err(eyre!("{err}")), Err(err)],
+    };
+
+    let rows = expected
+        .parts()
+        .into_iter()
+        .zip(actual)
+        .zip(1..)
+        .filter_map(|((expected, actual), part)| {
+            let expected = expected?.to_owned();
+            let (actual, status) = match actual {
+                Ok(actual) if same_answer(&expected, &actual) => (actual, Status::Ok),
+                Ok(actual) => (actual, Status::Mismatch),
+                Err(err) => (String::new(), Status::Error(err.to_string())),
+            };
+
+            Some(Row {
+                day,
+                data,
+                part,
+                expected,
+                actual,
+                status,
+            })
+        })
+        .collect();
+
+    Ok(rows)
+}
+
+fn print_table(rows: &[Row]) {
+    let header = ["day", "data", "part", "expected", "actual", "status"].map(str::to_owned);
+    let cells = rows
+        .iter()
+        .map(|row| {
+            [
+                row.day.to_string(),
+                row.data.to_owned(),
+                row.part.to_string(),
+                cell(&row.expected),
+                cell(&row.actual),
+                row.status.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let mut widths = header.clone().map(|cell| cell.len());
+    for row in &cells {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let print_row = |row: &[String]| {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    };
+
+    print_row(&header);
+    print_row(&widths.map(|width| "-".repeat(width)));
+    for row in &cells {
+        print_row(row);
+    }
+}
+
+pub fn check(days: impl Iterator<Item = u8>) -> Result<()> {
+    let mut rows = vec![];
+
+    for day in days {
+        let answers = Answers::load(day)?;
+
+        for (data, expected) in [("input", &answers.input), ("example", &answers.example)] {
+            if let Some(expected) = expected {
+                rows.extend(check_data(day, data, expected)?);
+            }
+        }
+    }
+
+    print_table(&rows);
+
+    // Multi-line answers are summarized in the table, so show them in full when they don't match
+    for row in rows.iter().filter(|row| row.status == Status::Mismatch) {
+        if row.expected.trim_matches('\n').contains('\n') || row.actual.contains('\n') {
+            let (day, data, part) = (row.day, row.data, row.part);
+            println!("\nday {day} {data} part {part}: expected");
+            println!("{}", row.expected.trim_matches('\n'));
+            println!("but got");
+            println!("{}", row.actual);
+        }
+    }
+
+    let failed = rows.iter().filter(|row| row.status != Status::Ok).count();
+    if failed > 0 {
+        return Err(eyre!("{failed} of {} checks failed", rows.len()));
+    }
+
+    println!("\nall {} checks passed", rows.len());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comparing_answers() {
+        assert!(same_answer("72478", "72478"));
+        assert!(!same_answer("72478", "72479"));
+        assert!(same_answer("\n##  #\n#  ##\n", "##  #  \n#  ##"));
+        assert!(!same_answer("\n##  #\n#  ##\n", "##  #\n## ##"));
+    }
+
+    #[test]
+    fn parsing_answers() {
+        let answers = toml::from_str::<Answers>(
+            r#"
+            [input]
+            part1 = "CMZ"
+
+            [example]
+            part1 = "1"
+            part2 = '''
+            ##
+            ##'''
+            "#,
+        )
+        .unwrap();
+
+        let input = answers.input.unwrap();
+        assert_eq!(input.parts(), [Some("CMZ"), None]);
+        assert_eq!(answers.example.unwrap().part2.unwrap().lines().count(), 2);
+    }
+
+    #[test]
+    fn windows_examples() {
+        for day in 1..=25 {
+            let Some(expected) = Answers::load(day).unwrap().example else {
+                continue;
+            };
+            let path = day_dir(day).join("data").join("example.txt");
+            let example = fs::read_to_string(path).unwrap();
+
+            // As saved by a Windows editor, with a BOM and a blank line at the end
+            let windows = format!("\u{feff}{}\r\n\r\n", example.replace('\n', "\r\n"));
+            let input = common::input::normalize(&windows);
+
+            for row in check_input(day, "example", &input, &expected).unwrap() {
+                assert_eq!(row.status, Status::Ok, "day {day} part {}", row.part);
+            }
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use color_eyre::{eyre::eyre, Report, Result};
+use std::{ops::RangeInclusive, str::FromStr};
+
+pub const FIRST_DAY: u8 = 1;
+pub const LAST_DAY: u8 = 25;
+
+// A selection of days given on the command line: "17", "1..=25", "1..26" or "all".
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Days(RangeInclusive<u8>);
+
+impl Days {
+    pub fn iter(&self) -> impl Iterator<Item = u8> {
+        self.0.clone()
+    }
+}
+
+fn parse_number(s: &str) -> Result<u8> {
+    s.trim().parse::<u8>().map_err(|_| eyre!("not a day: {s}"))
+}
+
+fn check_day(day: u8) -> Result<u8> {
+    if !(FIRST_DAY..=LAST_DAY).contains(&day) {
+        return Err(eyre!(
+            "day must be between {FIRST_DAY} and {LAST_DAY}: {day}"
+        ));
+    }
+
+    Ok(day)
+}
+
+impl FromStr for Days {
+    type Err = Report;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s == "all" {
+            return Ok(Self(FIRST_DAY..=LAST_DAY));
+        }
+
+        let range = if let Some((start, end)) = s.split_once("..=") {
+            check_day(parse_number(start)?)?..=check_day(parse_number(end)?)?
+        } else if let Some((start, end)) = s.split_once("..") {
+            let start = check_day(parse_number(start)?)?;
+            match parse_number(end)?.checked_sub(1) {
+                Some(end) if end >= start => start..=check_day(end)?,
+                _ => return Err(eyre!("no days selected: {s}")),
+            }
+        } else {
+            let day = check_day(parse_number(s)?)?;
+            day..=day
+        };
+
+        if range.is_empty() {
+            return Err(eyre!("no days selected: {s}"));
+        }
+
+        Ok(Self(range))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn days(s: &str) -> Vec<u8> {
+        s.parse::<Days>().unwrap().iter().collect()
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(days("17"), vec![17]);
+        assert_eq!(days("1..=3"), vec![1, 2, 3]);
+        assert_eq!(days("1..3"), vec![1, 2]);
+        assert_eq!(days("20..26"), vec![20, 21, 22, 23, 24, 25]);
+        assert_eq!(days("all").len(), 25);
+    }
+
+    #[test]
+    fn bad_input() {
+        assert!("0".parse::<Days>().is_err());
+        assert!("26".parse::<Days>().is_err());
+        assert!("3..=1".parse::<Days>().is_err());
+        assert!("1..1".parse::<Days>().is_err());
+        assert!("one".parse::<Days>().is_err());
+    }
+}
@@ -0,0 +1,36 @@
+// Looks up the generator of random puzzle inputs for a day
+use color_eyre::{eyre::eyre, Result};
+use common::generate::Generator;
+
+pub fn generator(day: u8) -> Result<Generator> {
+    let generator: Generator = match day {
+        1 => day01::generate::input,
+        2 => day02::generate::input,
+        3 => day03::generate::input,
+        4 => day04::generate::input,
+        5 => day05::generate::input,
+        6 => day06::generate::input,
+        7 => day07::generate::input,
+        8 => day08::generate::input,
+        9 => day09::generate::input,
+        10 => day10::generate::input,
+        11 => day11::generate::input,
+        12 => day12::generate::input,
+        13 => day13::generate::input,
+        14 => day14::generate::input,
+        15 => day15::generate::input,
+        16 => day16::generate::input,
+        17 => day17::generate::input,
+        18 => day18::generate::input,
+        19 => day19::generate::input,
+        20 => day20::generate::input,
+        21 => day21::generate::input,
+        22 => day22::generate::input,
+        23 => day23::generate::input,
+        24 => day24::generate::input,
+        25 => day25::generate::input,
+        _ => return Err(eyre!("no generator for day {day}")),
+    };
+
+    Ok(generator)
+}
@@ -0,0 +1,18 @@
+// Looks up the picture for a day with a map or a picture worth saving
+use color_eyre::{eyre::eyre, Result};
+use common::image::Drawer;
+
+pub fn drawer(day: u8) -> Result<Drawer> {
+    let drawer: Drawer = match day {
+        8 => day08::image::draw,
+        10 => day10::image::draw,
+        12 => day12::image::draw,
+        14 => day14::image::draw,
+        17 => day17::image::draw,
+        22 => day22::image::draw,
+        23 => day23::image::draw,
+        _ => return Err(eyre!("no image for day {day}")),
+    };
+
+    Ok(drawer)
+}
@@ -0,0 +1,310 @@
+// Runs the solutions for one or more days against their puzzle input:
+//
+//   cargo run --release -p aoc -- 17
+//   cargo run --release -p aoc -- 1..=25
+//   cargo run --release -p aoc -- all
+//   cargo run --release -p aoc -- 17 --input path/to/input.txt
+//
+// By default the input for a day is read from dayNN/data/input.txt.  With --format json, a JSON
+// record is printed on its own line for each part:
+//
+//   {"day":10,"part":2,"answer":"RBPARAGF","pixels":["###  ###  ..."],"parse_ns":..,"solve_ns":..}
+//
+// The answers can be checked against those recorded in dayNN/answers.toml with:
+//
+//   cargo run --release -p aoc -- check all
+//
+// A random input for a day, of a given size and from a given seed, is printed with:
+//
+//   cargo run --release -p aoc -- generate 16 --size 10 --seed 7 > valves.txt
+//
+// The days that step through a simulation (9, 14, 17, 23 and 24) can show each step in the
+// terminal instead, with the part of each frame to show given as WIDTHxHEIGHT+X+Y:
+//
+//   cargo run --release -p aoc -- 23 --animate --fps 10 --viewport 60x30+40+20
+//
+// The days with a map or a picture (8, 10, 12, 14, 17, 22 and 23) can save it as a PPM or a PNG
+// file, with each cell drawn as a square of pixels:
+//
+//   cargo run --release -p aoc -- 12 --image map.png --scale 4
+//
+// The solutions can also be served over HTTP, with the input POSTed to /day/{n}/part/{p} and the
+// answer sent back as a JSON record:
+//
+//   cargo run --release -p aoc -- serve --addr 127.0.0.1:8022 --max-body 1048576 --timeout 30 --workers 8
+use clap::{Parser, Subcommand, ValueEnum};
+use color_eyre::{eyre::eyre, Result};
+use common::animate::Viewport;
+use serde::Serialize;
+use std::{
+    io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+mod animate;
+mod check;
+mod days;
+mod generate;
+mod image;
+mod serve;
+mod solver;
+use days::Days;
+use solver::{solver, Part};
+
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+#[derive(Debug, Parser)]
+#[command(
+    about = "Run the Advent of Code 2022 solutions",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+struct Args {
+    /// The days to run: a single day ("17"), a range ("1..=25", "1..26") or "all"
+    #[arg(required = true)]
+    days: Option<Days>,
+
+    /// Read the puzzle input from this file instead of dayNN/data/input.txt
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Print the answers as text or as a JSON record per part
+    #[arg(short, long, value_enum, default_value_t)]
+    format: Format,
+
+    /// Show each step of the day's simulation in the terminal instead of printing the answers
+    #[arg(long)]
+    animate: bool,
+
+    /// Frames per second to animate at, or 0 to go as fast as possible
+    #[arg(long, default_value_t = 20.0, requires = "animate")]
+    fps: f64,
+
+    /// The part of each frame to show, as WIDTHxHEIGHT or WIDTHxHEIGHT+X+Y from the top left
+    #[arg(long, default_value = "80x40", requires = "animate")]
+    viewport: Viewport,
+
+    /// Save a picture of the day's map to a .ppm or .png file instead of printing the answers
+    #[arg(long, conflicts_with = "animate")]
+    image: Option<PathBuf>,
+
+    /// How many pixels wide to draw each cell of the picture
+    #[arg(long, default_value_t = 4, requires = "image")]
+    scale: usize,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Check the answers for data/input.txt and data/example.txt against dayNN/answers.toml
+    Check {
+        #[arg(default_value = "all")]
+        days: Days,
+    },
+
+    /// Print a random puzzle input for a day
+    Generate {
+        day: u8,
+
+        /// How big the input is, which means something different for each day, and at least 1
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+
+        /// The same seed always gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+
+    /// Serve the solutions over HTTP, at POST /day/{n}/part/{p} with the input as the body
+    Serve {
+        #[arg(long, default_value = "127.0.0.1:8022")]
+        addr: String,
+
+        /// The largest input accepted, in bytes
+        #[arg(long, default_value_t = 1 << 20)]
+        max_body: usize,
+
+        /// How many seconds a part is given to be solved before the request is answered with a 504
+        #[arg(long, default_value_t = 30)]
+        timeout: u64,
+
+        /// How many requests are handled, and how many parts solved, at once
+        #[arg(long, default_value_t = 8)]
+        workers: usize,
+    },
+}
+
+#[derive(Debug, Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    // The picture that was read to get the answer, one row per line
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pixels: Option<Vec<&'a str>>,
+    parse_ns: u64,
+    solve_ns: u64,
+}
+
+impl<'a> Record<'a> {
+    fn new(day: u8, part: u8, solved: &'a Part, parse_ns: u64) -> Self {
+        let (answer, pixels) = match &solved.decoded {
+            Some(decoded) => (decoded.as_str(), Some(solved.answer.lines().collect())),
+            None => (solved.answer.as_str(), None),
+        };
+
+        Self {
+            day,
+            part,
+            answer,
+            pixels,
+            parse_ns,
+            solve_ns: solved.solve_ns,
+        }
+    }
+}
+
+fn day_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{day:02}"))
+}
+
+fn default_input(day: u8) -> PathBuf {
+    day_dir(day).join("data").join("input.txt")
+}
+
+fn report(part: u8, answer: &str) {
+    if answer.contains('\n') {
+        println!("  part {part}:\n{answer}");
+    } else {
+        println!("  part {part}: {answer}");
+    }
+}
+
+fn run(day: u8, input: &str, format: Format) -> Result<()> {
+    let solved = solver(day)?(input)?.solve_both();
+
+    if let Format::Text = format {
+        println!("day {day}");
+    }
+
+    for (part, solution) in (1..).zip(solved.parts) {
+        let solution = solution?;
+
+        match format {
+            Format::Text => report(part, &solution.answer),
+            Format::Json => {
+                let record = Record::new(day, part, &solution, solved.parse_ns);
+                println!("{}", serde_json::to_string(&record)?);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+    let args = Args::parse();
+
+    match args.command {
+        Some(Command::Check { days }) => return check::check(days.iter()),
+        Some(Command::Generate { day, size, seed }) => {
+            if size == 0 {
+                return Err(eyre!("an input has to be at least --size 1"));
+            }
+            let generator = generate::generator(day)?;
+            print!("{}", generator(&mut common::generate::rng(seed), size));
+            return Ok(());
+        }
+        Some(Command::Serve {
+            addr,
+            max_body,
+            timeout,
+            workers,
+        }) => {
+            let limits = serve::Limits {
+                max_body,
+                timeout: Duration::from_secs(timeout),
+                workers,
+            };
+            return serve::serve(&addr, limits);
+        }
+        None => {}
+    }
+
+    let days = args.days.ok_or_else(|| eyre!("no days given"))?;
+    if args.input.is_some() && days.iter().count() > 1 {
+        return Err(eyre!("an input file can only be given for a single day"));
+    }
+    if args.animate && days.iter().count() > 1 {
+        return Err(eyre!("only a single day can be animated"));
+    }
+    if args.image.is_some() && days.iter().count() > 1 {
+        return Err(eyre!("only a single day can be saved as an image"));
+    }
+    if args.scale == 0 {
+        return Err(eyre!("the scale of an image must be at least 1"));
+    }
+
+    for day in days.iter() {
+        let path = args.input.clone().unwrap_or_else(|| default_input(day));
+        let input = common::input::read_file(&path)?;
+
+        if args.animate {
+            let mut animation = animate::animator(day)?(&input)?;
+            common::animate::play(&mut *animation, args.fps, args.viewport, &mut io::stdout())?;
+            continue;
+        }
+
+        if let Some(path) = &args.image {
+            image::drawer(day)?(&input)?.scale(args.scale).save(path)?;
+            continue;
+        }
+
+        run(day, &input, args.format)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_records() {
+        let solved = Part {
+            answer: "#.\n.#".into(),
+            decoded: Some("X".into()),
+            solve_ns: 20,
+        };
+        let record = Record::new(10, 2, &solved, 10);
+
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r##"{"day":10,"part":2,"answer":"X","pixels":["#.",".#"],"parse_ns":10,"solve_ns":20}"##
+        );
+
+        let solved = Part {
+            answer: "42".into(),
+            decoded: None,
+            solve_ns: 20,
+        };
+        let record = Record::new(1, 1, &solved, 10);
+
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r#"{"day":1,"part":1,"answer":"42","parse_ns":10,"solve_ns":20}"#
+        );
+    }
+}
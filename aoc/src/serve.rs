@@ -0,0 +1,342 @@
+// Serves the solutions over HTTP, for tools that would rather not run a binary for each day:
+//
+//   cargo run --release -p aoc -- serve --addr 127.0.0.1:8022
+//   curl --data-binary @day16/data/input.txt http://127.0.0.1:8022/day/16/part/2
+//
+// Only the part asked for is solved, and the answer comes back as the same JSON record that
+// --format json prints.  Inputs bigger than --max-body are turned away, and a part that takes
+// longer than --timeout to solve, as the searches of days 16 and 19 can, is given up on with a 504.
+// There's no stopping a solver part-way through, so it carries on in the background until it's
+// done and its answer is thrown away.  Requests are handled by --workers threads, and no more than
+// --workers solvers run at once, counting the ones given up on, so a request that comes in while
+// they're all busy is turned away with a 503.
+use crate::{solver::solver, Record};
+use color_eyre::{eyre::eyre, Result};
+use serde_json::json;
+use std::{
+    fmt::Display,
+    io::Read,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+#[derive(Clone, Copy, Debug)]
+pub struct Limits {
+    // The largest input accepted, in bytes
+    pub max_body: usize,
+    pub timeout: Duration,
+    // The number of requests handled, and of solvers run, at once
+    pub workers: usize,
+}
+
+#[derive(Debug)]
+struct Reply {
+    status: u16,
+    body: String,
+}
+
+impl Reply {
+    fn error(status: u16, message: impl Display) -> Self {
+        Self {
+            status,
+            body: json!({ "error": message.to_string() }).to_string(),
+        }
+    }
+}
+
+// The day and the part from a path like /day/16/part/2
+fn route(method: &Method, url: &str) -> Result<(u8, u8), Reply> {
+    let path = url.split('?').next().unwrap_or_default();
+    let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+
+    let (day, part) = match segments[..] {
+        ["day", day, "part", part @ ("1" | "2")] => match (day.parse(), part.parse()) {
+            (Ok(day), Ok(part)) => (day, part),
+            _ => return Err(Reply::error(404, format!("no such page: {path}"))),
+        },
+        _ => return Err(Reply::error(404, format!("no such page: {path}"))),
+    };
+
+    if *method != Method::Post {
+        return Err(Reply::error(405, "the input has to be POSTed"));
+    }
+
+    Ok((day, part))
+}
+
+fn read_input(body: impl Read, length: Option<usize>, max_body: usize) -> Result<String, Reply> {
+    let too_large = || Reply::error(413, format!("the input is over {max_body} bytes"));
+    if length.map_or(false, |length| length > max_body) {
+        return Err(too_large());
+    }
+
+    // The length isn't always given up front, so no more than one byte too many is read
+    let mut input = vec![];
+    body.take(max_body as u64 + 1)
+        .read_to_end(&mut input)
+        .map_err(|err| Reply::error(400, format!("failed to read the input: {err}")))?;
+    if input.len() > max_body {
+        return Err(too_large());
+    }
+
+    let input =
+        String::from_utf8(input).map_err(|_| Reply::error(400, "the input is not UTF-8"))?;
+    Ok(common::input::normalize(&input))
+}
+
+// The solvers running, including the ones that have been given up on but haven't finished yet
+struct Solvers {
+    running: AtomicUsize,
+    max: usize,
+}
+
+// A place among the running solvers, given up when the solver finishes
+struct Running(Arc<Solvers>);
+
+impl Solvers {
+    fn new(max: usize) -> Arc<Self> {
+        Arc::new(Self {
+            running: AtomicUsize::new(0),
+            max,
+        })
+    }
+
+    fn start(self: &Arc<Self>) -> Option<Running> {
+        self.running
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |running| {
+                (running < self.max).then_some(running + 1)
+            })
+            .ok()?;
+        Some(Running(self.clone()))
+    }
+}
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        self.0.running.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+// Runs a job on a thread of its own, and gives up on it if it takes longer than `timeout`
+fn within<T: Send + 'static>(
+    solvers: &Arc<Solvers>,
+    timeout: Duration,
+    job: impl FnOnce() -> Result<T> + Send + 'static,
+) -> Result<T, Reply> {
+    let running = solvers
+        .start()
+        .ok_or_else(|| Reply::error(503, format!("{} solvers are already running", solvers.max)))?;
+
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let done = job();
+        drop(running);
+        // Nobody is waiting for the answer any more if the request has timed out
+        let _ = sender.send(done);
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(Ok(done)) => Ok(done),
+        Ok(Err(err)) => Err(Reply::error(422, err)),
+        Err(RecvTimeoutError::Timeout) => {
+            Err(Reply::error(504, format!("no answer within {timeout:?}")))
+        }
+        Err(RecvTimeoutError::Disconnected) => Err(Reply::error(500, "the solver panicked")),
+    }
+}
+
+fn solve(day: u8, part: u8, input: String, solvers: &Arc<Solvers>, timeout: Duration) -> Reply {
+    let solver = match solver(day) {
+        Ok(solver) => solver,
+        Err(err) => return Reply::error(404, err),
+    };
+
+    let job = move || solver(&input).and_then(|parsed| Ok((parsed.parse_ns, parsed.solve(part)?)));
+    match within(solvers, timeout, job) {
+        Ok((parse_ns, solved)) => Reply {
+            status: 200,
+            body: serde_json::to_string(&Record::new(day, part, &solved, parse_ns)).unwrap(),
+        },
+        Err(reply) => reply,
+    }
+}
+
+fn reply(
+    method: &Method,
+    url: &str,
+    body: impl Read,
+    length: Option<usize>,
+    solvers: &Arc<Solvers>,
+    limits: Limits,
+) -> Reply {
+    let (day, part) = match route(method, url) {
+        Ok(route) => route,
+        Err(reply) => return reply,
+    };
+
+    match read_input(body, length, limits.max_body) {
+        Ok(input) => solve(day, part, input, solvers, limits.timeout),
+        Err(reply) => reply,
+    }
+}
+
+fn handle(mut request: Request, solvers: &Arc<Solvers>, limits: Limits) {
+    let (method, url, length) = (
+        request.method().clone(),
+        request.url().to_owned(),
+        request.body_length(),
+    );
+    let Reply { status, body } = reply(&method, &url, request.as_reader(), length, solvers, limits);
+
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+    let response = Response::from_string(body)
+        .with_status_code(status)
+        .with_header(content_type);
+    if let Err(err) = request.respond(response) {
+        eprintln!("failed to answer {method} {url}: {err}");
+    }
+}
+
+pub fn serve(addr: &str, limits: Limits) -> Result<()> {
+    if limits.workers == 0 {
+        return Err(eyre!("there has to be at least one worker"));
+    }
+    let server = Server::http(addr).map_err(|err| eyre!("failed to listen on {addr}: {err}"))?;
+    let server = Arc::new(server);
+    let solvers = Solvers::new(limits.workers);
+    eprintln!("listening on http://{addr}");
+
+    // Requests are shared out between the workers, so that a slow search only holds up its own
+    let workers = (0..limits.workers)
+        .map(|_| {
+            let (server, solvers) = (server.clone(), solvers.clone());
+            thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    handle(request, &solvers, limits);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for worker in workers {
+        worker
+            .join()
+            .map_err(|_| eyre!("a worker serving requests panicked"))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    const LIMITS: Limits = Limits {
+        max_body: 1024,
+        timeout: Duration::from_secs(10),
+        workers: 4,
+    };
+
+    fn reply(
+        method: &Method,
+        url: &str,
+        body: impl Read,
+        length: Option<usize>,
+        limits: Limits,
+    ) -> Reply {
+        super::reply(
+            method,
+            url,
+            body,
+            length,
+            &Solvers::new(limits.workers),
+            limits,
+        )
+    }
+
+    fn post(url: &str, input: &str, limits: Limits) -> (u16, Value) {
+        let reply = reply(&Method::Post, url, input.as_bytes(), None, limits);
+        (reply.status, serde_json::from_str(&reply.body).unwrap())
+    }
+
+    #[test]
+    fn answer() {
+        let input = include_str!("../../day01/data/example.txt");
+        let (status, body) = post("/day/1/part/2", input, LIMITS);
+
+        assert_eq!(status, 200);
+        assert_eq!(body["day"], 1);
+        assert_eq!(body["part"], 2);
+        assert_eq!(body["answer"], "45000");
+        assert!(body["solve_ns"].is_u64());
+    }
+
+    #[test]
+    fn routes() {
+        assert_eq!(post("/day/1/part/3", "", LIMITS).0, 404);
+        assert_eq!(post("/day/x/part/1", "", LIMITS).0, 404);
+        assert_eq!(post("/day/26/part/1", "", LIMITS).0, 404);
+        assert_eq!(post("/days", "", LIMITS).0, 404);
+
+        let reply = reply(&Method::Get, "/day/1/part/1", &b""[..], None, LIMITS);
+        assert_eq!(reply.status, 405);
+    }
+
+    #[test]
+    fn bad_input() {
+        assert_eq!(post("/day/1/part/1", "1000\nlots\n", LIMITS).0, 422);
+
+        let reply = reply(&Method::Post, "/day/1/part/1", &[0xff][..], None, LIMITS);
+        assert_eq!(reply.status, 400);
+    }
+
+    #[test]
+    fn size_limit() {
+        let input = "1000\n".repeat(205);
+        assert_eq!(post("/day/1/part/1", &input[..1024], LIMITS).0, 200);
+        assert_eq!(post("/day/1/part/1", &input, LIMITS).0, 413);
+
+        let reply = reply(&Method::Post, "/day/1/part/1", &b""[..], Some(2048), LIMITS);
+        assert_eq!(reply.status, 413);
+    }
+
+    #[test]
+    fn timeout() {
+        let solvers = Solvers::new(1);
+
+        // A job that can't finish until the test lets it
+        let (release, blocked) = mpsc::channel::<()>();
+        let job = move || Ok(blocked.recv()?);
+        let reply = within(&solvers, Duration::from_millis(10), job).unwrap_err();
+
+        assert_eq!(reply.status, 504);
+        let body = serde_json::from_str::<Value>(&reply.body).unwrap();
+        assert!(body["error"]
+            .as_str()
+            .unwrap()
+            .starts_with("no answer within"));
+
+        // The job given up on still takes up the one place until it's done
+        assert_eq!(solvers.running.load(Ordering::SeqCst), 1);
+        let reply = within(&solvers, LIMITS.timeout, || Ok(7)).unwrap_err();
+        assert_eq!(reply.status, 503);
+
+        release.send(()).unwrap();
+        while solvers.running.load(Ordering::SeqCst) > 0 {
+            thread::yield_now();
+        }
+        assert_eq!(within(&solvers, LIMITS.timeout, || Ok(7)).unwrap(), 7);
+
+        let reply = within(&solvers, LIMITS.timeout, || -> Result<()> { panic!() }).unwrap_err();
+        assert_eq!(reply.status, 500);
+        while solvers.running.load(Ordering::SeqCst) > 0 {
+            thread::yield_now();
+        }
+    }
+}
@@ -0,0 +1,111 @@
+// Looks up the solution for a day and runs it, timing the parse and each of the parts.
+use color_eyre::{eyre::eyre, Result};
+use common::{Answer, Solution};
+use std::time::Instant;
+
+pub struct Part {
+    // The answer as it is displayed
+    pub answer: String,
+    // The text read from answers that are pictures
+    pub decoded: Option<String>,
+    pub solve_ns: u64,
+}
+
+impl Part {
+    fn solve<A: Answer>(f: impl FnOnce() -> Result<A>) -> Result<Self> {
+        let start = Instant::now();
+        let answer = f()?;
+        let solve_ns = start.elapsed().as_nanos() as u64;
+
+        Ok(Self {
+            answer: answer.to_string(),
+            decoded: answer.decoded(),
+            solve_ns,
+        })
+    }
+}
+
+// Each part is solved even if the other fails
+pub struct Solved {
+    pub parse_ns: u64,
+    pub parts: [Result<Part>; 2],
+}
+
+trait Parts {
+    fn solve(&self, part: u8) -> Result<Part>;
+}
+
+impl<S: Solution> Parts for S {
+    fn solve(&self, part: u8) -> Result<Part> {
+        match part {
+            1 => Part::solve(|| self.part1()),
+            2 => Part::solve(|| self.part2()),
+            _ => Err(eyre!("no part {part}")),
+        }
+    }
+}
+
+// An input parsed for a day, ready for either part to be solved on its own
+pub struct Parsed {
+    pub parse_ns: u64,
+    solution: Box<dyn Parts>,
+}
+
+impl Parsed {
+    pub fn solve(&self, part: u8) -> Result<Part> {
+        self.solution.solve(part)
+    }
+
+    pub fn solve_both(self) -> Solved {
+        Solved {
+            parse_ns: self.parse_ns,
+            parts: [self.solve(1), self.solve(2)],
+        }
+    }
+}
+
+pub type Solver = fn(&str) -> Result<Parsed>;
+
+fn parse<S: Solution + 'static>(input: &str) -> Result<Parsed> {
+    let start = Instant::now();
+    let solution = S::parse(input)?;
+    let parse_ns = start.elapsed().as_nanos() as u64;
+
+    Ok(Parsed {
+        parse_ns,
+        solution: Box::new(solution),
+    })
+}
+
+pub fn solver(day: u8) -> Result<Solver> {
+    let solver: Solver = match day {
+        1 => parse::<day01::Runner>,
+        2 => parse::<day02::Runner>,
+        3 => parse::<day03::Calculations>,
+        4 => parse::<day04::Task>,
+        5 => parse::<day05::Task>,
+        6 => parse::<day06::Task>,
+        7 => parse::<day07::Task>,
+        8 => parse::<day08::Task>,
+        9 => parse::<day09::Task>,
+        10 => parse::<day10::Task>,
+        11 => parse::<day11::Task>,
+        12 => parse::<day12::Task>,
+        13 => parse::<day13::Task>,
+        14 => parse::<day14::Task>,
+        15 => parse::<day15::Task>,
+        16 => parse::<day16::Task>,
+        17 => parse::<day17::cycles::Task>,
+        18 => parse::<day18::dfs1::Task>,
+        19 => parse::<day19::branch1::Task>,
+        20 => parse::<day20::naive::Task>,
+        21 => parse::<day21::solve::Task>,
+        22 => parse::<day22::Task>,
+        23 => parse::<day23::Task>,
+        24 => parse::<day24::Task>,
+        25 => parse::<day25::Task>,
+        _ => return Err(eyre!("no solution for day {day}")),
+    };
+
+    Ok(solver)
+}
@@ -0,0 +1,172 @@
+// Simulations shown in the terminal a step at a time, to watch what a solution does with an input.
+// The days that step through a simulation have an `animate::animation` that starts one from an
+// input, and the runner plays it with:
+//
+//   cargo run --release -p aoc -- 17 --animate --fps 30 --viewport 40x30
+//
+// Every frame is drawn in full and then cropped to the viewport, so a frame can be as big as the
+// simulation needs.
+use color_eyre::{eyre::eyre, Report, Result};
+use std::{io::Write, str::FromStr, thread, time::Duration};
+
+pub trait Animation {
+    // Moves the simulation on by a step, or returns false once it has come to an end
+    fn step(&mut self) -> bool;
+
+    // The state of the simulation, one line per row
+    fn frame(&self) -> String;
+}
+
+pub type Animator = fn(&str) -> Result<Box<dyn Animation>>;
+
+// The part of each frame that is shown: `width` columns and `height` rows, starting from column `x`
+// and row `y`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Viewport {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Self {
+            x: 0,
+            y: 0,
+            width: 80,
+            height: 40,
+        }
+    }
+}
+
+// WIDTHxHEIGHT, optionally followed by +X+Y, as in "80x40" or "80x40+10+5"
+impl FromStr for Viewport {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bad = || eyre!("expected a viewport like 80x40 or 80x40+10+5: {s}");
+
+        let (size, offset) = match s.split_once('+') {
+            Some((size, offset)) => (size, Some(offset)),
+            None => (s, None),
+        };
+        let (width, height) = size.split_once('x').ok_or_else(bad)?;
+        let (x, y) = match offset {
+            Some(offset) => offset.split_once('+').ok_or_else(bad)?,
+            None => ("0", "0"),
+        };
+
+        let viewport = Self {
+            x: x.parse().map_err(|_| bad())?,
+            y: y.parse().map_err(|_| bad())?,
+            width: width.parse().map_err(|_| bad())?,
+            height: height.parse().map_err(|_| bad())?,
+        };
+        if viewport.width == 0 || viewport.height == 0 {
+            return Err(bad());
+        }
+
+        Ok(viewport)
+    }
+}
+
+impl Viewport {
+    pub fn crop(&self, frame: &str) -> Vec<String> {
+        frame
+            .lines()
+            .skip(self.y)
+            .take(self.height)
+            .map(|line| line.chars().skip(self.x).take(self.width).collect())
+            .collect()
+    }
+}
+
+// Draws each step of the animation over the last, until it comes to an end, and returns the number
+// of steps taken.  A frame rate of zero plays it as fast as it can be drawn.
+pub fn play<W: Write>(
+    animation: &mut dyn Animation,
+    fps: f64,
+    viewport: Viewport,
+    out: &mut W,
+) -> Result<usize> {
+    let delay = match fps {
+        fps if fps > 0.0 => Duration::from_secs_f64(1.0 / fps),
+        _ => Duration::ZERO,
+    };
+
+    // Clear the screen once, and then draw each frame from the top left corner, clearing what's
+    // left of the last frame after each line and below the last one
+    write!(out, "\x1b[2J")?;
+    let mut steps = 0;
+
+    loop {
+        write!(out, "\x1b[H")?;
+        for line in viewport.crop(&animation.frame()) {
+            writeln!(out, "{line}\x1b[K")?;
+        }
+        writeln!(out, "\x1b[Jstep {steps}")?;
+        out.flush()?;
+        thread::sleep(delay);
+
+        if !animation.step() {
+            break;
+        }
+        steps += 1;
+    }
+
+    Ok(steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Countdown(usize);
+
+    impl Animation for Countdown {
+        fn step(&mut self) -> bool {
+            self.0 = self.0.saturating_sub(1);
+            self.0 > 0
+        }
+
+        fn frame(&self) -> String {
+            format!("{}\n", self.0)
+        }
+    }
+
+    #[test]
+    fn viewports() {
+        assert_eq!("80x40".parse::<Viewport>().unwrap(), Viewport::default());
+        assert_eq!(
+            "20x10+3+4".parse::<Viewport>().unwrap(),
+            Viewport {
+                x: 3,
+                y: 4,
+                width: 20,
+                height: 10
+            }
+        );
+        assert!("20".parse::<Viewport>().is_err());
+        assert!("20x10+3".parse::<Viewport>().is_err());
+        assert!("0x10".parse::<Viewport>().is_err());
+    }
+
+    #[test]
+    fn crop() {
+        let viewport = "2x2+1+1".parse::<Viewport>().unwrap();
+        assert_eq!(viewport.crop("abc\ndef\nghi\n"), vec!["ef", "hi"]);
+        assert_eq!(viewport.crop("abc\nd\n"), vec![""]);
+    }
+
+    #[test]
+    fn plays_to_the_end() {
+        let mut out = vec![];
+        let steps = play(&mut Countdown(3), 0.0, Viewport::default(), &mut out).unwrap();
+        assert_eq!(steps, 2);
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("3\x1b[K\n"));
+        assert!(out.ends_with("1\x1b[K\n\x1b[Jstep 2\n"));
+    }
+}
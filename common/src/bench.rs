@@ -0,0 +1,85 @@
+// Criterion helpers shared by the benchmarks in each day crate.  Parsing and the two parts are
+// measured separately, and when a day has more than one implementation they are put in the same
+// benchmark group so that criterion reports them side by side:
+//
+//   common::benchmarks!("day17", include_str!("../data/input.txt"),
+//       chamber => day17::chamber::Task,
+//       cycles => day17::cycles::Task,
+//   );
+use crate::Solution;
+use criterion::{measurement::WallTime, BenchmarkGroup};
+use std::hint::black_box;
+
+pub use criterion;
+
+pub type Group<'c> = BenchmarkGroup<'c, WallTime>;
+
+pub fn parse<S: Solution>(group: &mut Group, name: &str, input: &str) {
+    if let Err(err) = S::parse(input) {
+        eprintln!("skipping {name}: {err}");
+        return;
+    }
+
+    group.bench_function(name, |b| b.iter(|| S::parse(black_box(input))));
+}
+
+// Some implementations only solve one of the parts, so a part is benchmarked only if it can be
+// solved.
+fn part<S: Solution, A>(
+    group: &mut Group,
+    name: &str,
+    input: &str,
+    solve: impl Fn(&S) -> color_eyre::Result<A>,
+) {
+    let solution = match S::parse(input) {
+        Ok(solution) => solution,
+        Err(err) => {
+            eprintln!("skipping {name}: {err}");
+            return;
+        }
+    };
+
+    if let Err(err) = solve(&solution) {
+        eprintln!("skipping {name}: {err}");
+        return;
+    }
+
+    group.bench_function(name, |b| b.iter(|| solve(black_box(&solution))));
+}
+
+pub fn part1<S: Solution>(group: &mut Group, name: &str, input: &str) {
+    part(group, name, input, S::part1);
+}
+
+pub fn part2<S: Solution>(group: &mut Group, name: &str, input: &str) {
+    part(group, name, input, S::part2);
+}
+
+#[macro_export]
+macro_rules! benchmarks {
+    ($day:literal, $input:expr, $($name:ident => $solution:ty),+ $(,)?) => {
+        fn benchmarks(c: &mut $crate::bench::criterion::Criterion) {
+            let input = $crate::input::normalize($input);
+            let input = input.as_str();
+
+            let mut group = c.benchmark_group(concat!($day, "/parse"));
+            $($crate::bench::parse::<$solution>(&mut group, stringify!($name), input);)+
+            group.finish();
+
+            let mut group = c.benchmark_group(concat!($day, "/part1"));
+            $($crate::bench::part1::<$solution>(&mut group, stringify!($name), input);)+
+            group.finish();
+
+            let mut group = c.benchmark_group(concat!($day, "/part2"));
+            $($crate::bench::part2::<$solution>(&mut group, stringify!($name), input);)+
+            group.finish();
+        }
+
+        $crate::bench::criterion::criterion_group!(benches, benchmarks);
+        $crate::bench::criterion::criterion_main!(benches);
+    };
+
+    ($day:literal, $input:expr, $solution:ty) => {
+        $crate::benchmarks!($day, $input, solution => $solution);
+    };
+}
@@ -0,0 +1,39 @@
+// Random puzzle inputs, for trying the solutions on more than the one input that each day comes
+// with.  Every day has a generator that makes a valid input of a given size, which means something
+// different from one day to the next (the number of elves, the width of a map, ...).  The same seed
+// always gives the same input:
+//
+//   let input = day16::generate::input(&mut common::generate::rng(7), 10);
+//
+// The runner prints them with:
+//
+//   cargo run --release -p aoc -- generate 16 --size 10 --seed 7
+use crate::Solution;
+use color_eyre::{eyre::WrapErr, Result};
+use rand::SeedableRng;
+use std::ops::Range;
+
+pub use rand::{self, rngs::StdRng, seq::SliceRandom, Rng};
+
+pub type Generator = fn(&mut StdRng, usize) -> String;
+
+pub fn rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+// Parses and solves both parts of the inputs made for each of the seeds
+pub fn check<S: Solution>(generator: Generator, size: usize, seeds: Range<u64>) -> Result<()> {
+    for seed in seeds {
+        let input = generator(&mut rng(seed), size);
+        let solve = || -> Result<()> {
+            let solution = S::parse(&input)?;
+            solution.part1()?;
+            solution.part2()?;
+            Ok(())
+        };
+
+        solve().wrap_err_with(|| format!("generated input of size {size} with seed {seed}"))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,182 @@
+// Pictures of puzzle states, for reports.  The days with a map or a picture worth seeing have an
+// `image::draw` that draws it from an input, each with its own palette, and the runner saves it as
+// a PPM or a PNG file depending on the extension given:
+//
+//   cargo run --release -p aoc -- 12 --image map.png --scale 4
+//
+// Each cell of a day's grid is a single pixel, and scaling the image up makes a square of pixels
+// out of each one.
+use color_eyre::{eyre::eyre, Result};
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+pub type Rgb = [u8; 3];
+
+pub type Drawer = fn(&str) -> Result<Image>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    // An image with the color of each pixel worked out from its column and row
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> Rgb,
+    {
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    // Pixels outside the image are left out
+    pub fn set(&mut self, x: usize, y: usize, color: Rgb) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = color;
+        }
+    }
+
+    // Each pixel becomes a square of pixels `factor` wide
+    pub fn scale(&self, factor: usize) -> Self {
+        Self::from_fn(self.width * factor, self.height * factor, |x, y| {
+            self.pixels[(y / factor) * self.width + x / factor]
+        })
+    }
+
+    // Binary PPM, as P6 with eight bits for each color
+    pub fn write_ppm<W: Write>(&self, mut out: W) -> Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.pixels.concat())?;
+        Ok(())
+    }
+
+    pub fn write_png<W: Write>(&self, out: W) -> Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width.try_into()?, self.height.try_into()?);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels.concat())?;
+        Ok(())
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let extension = path.extension().and_then(|extension| extension.to_str());
+        let write: fn(&Self, BufWriter<File>) -> Result<()> = match extension {
+            Some("ppm") => Self::write_ppm,
+            Some("png") => Self::write_png,
+            _ => return Err(eyre!("expected a .ppm or .png file: {}", path.display())),
+        };
+
+        let file = File::create(path)
+            .map_err(|err| eyre!("failed to create {}: {err}", path.display()))?;
+        write(self, BufWriter::new(file))
+    }
+}
+
+// The color a fraction `t` of the way from one color to another, for shading heights and the like
+pub fn blend(from: Rgb, to: Rgb, t: f64) -> Rgb {
+    let t = t.clamp(0.0, 1.0);
+    [0, 1, 2].map(|i| (from[i] as f64 + (to[i] as f64 - from[i] as f64) * t).round() as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgb = [255, 0, 0];
+    const BLUE: Rgb = [0, 0, 255];
+
+    fn image() -> Image {
+        Image::from_fn(3, 2, |x, y| if x == y { RED } else { BLUE })
+    }
+
+    #[test]
+    fn pixels() {
+        let mut image = image();
+        assert_eq!(image.get(1, 1), Some(RED));
+        assert_eq!(image.get(2, 1), Some(BLUE));
+        assert_eq!(image.get(3, 0), None);
+
+        image.set(2, 1, RED);
+        image.set(5, 5, RED);
+        assert_eq!(image.get(2, 1), Some(RED));
+    }
+
+    #[test]
+    fn scale() {
+        let image = image().scale(2);
+        assert_eq!((image.width(), image.height()), (6, 4));
+        assert_eq!(image.get(1, 1), Some(RED));
+        assert_eq!(image.get(2, 1), Some(BLUE));
+        assert_eq!(image.get(3, 3), Some(RED));
+    }
+
+    #[test]
+    fn ppm() {
+        let mut out = vec![];
+        image().write_ppm(&mut out).unwrap();
+
+        assert!(out.starts_with(b"P6\n3 2\n255\n"));
+        assert_eq!(out.len(), 11 + 3 * 2 * 3);
+        assert_eq!(out[11..14], RED);
+    }
+
+    #[test]
+    fn png() {
+        let mut out = vec![];
+        image().write_png(&mut out).unwrap();
+
+        let mut reader = png::Decoder::new(&out[..]).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+
+        assert_eq!((info.width, info.height), (3, 2));
+        assert_eq!(pixels, image().pixels.concat());
+    }
+
+    #[test]
+    fn blending() {
+        assert_eq!(blend(RED, BLUE, 0.0), RED);
+        assert_eq!(blend(RED, BLUE, 0.5), [128, 0, 128]);
+        assert_eq!(blend(RED, BLUE, 2.0), BLUE);
+    }
+
+    #[test]
+    fn unknown_extension() {
+        assert!(image().save(Path::new("image.gif")).is_err());
+    }
+}
@@ -0,0 +1,69 @@
+// Puzzle inputs as the days expect them.  An input saved on Windows comes with \r\n line endings,
+// and one that's been through an editor may start with a byte order mark or end with blank lines,
+// none of which the parsers are written to cope with.  Every entry point reads its input through
+// here, so that the days only ever see \n line endings and a single newline at the end.
+use color_eyre::{eyre::eyre, Result};
+use std::{
+    fs,
+    io::{self, Read},
+    path::Path,
+};
+
+const BOM: char = '\u{feff}';
+
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix(BOM).unwrap_or(input);
+    let input = input.replace("\r\n", "\n").replace('\r', "\n");
+
+    // Blank lines at the end go, but trailing spaces on the last line may be part of a map
+    let mut lines = input.lines().collect::<Vec<_>>();
+    while lines.last().map_or(false, |line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    lines.into_iter().flat_map(|line| [line, "\n"]).collect()
+}
+
+pub fn read(mut reader: impl Read) -> Result<String> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    Ok(normalize(&input))
+}
+
+pub fn stdin() -> Result<String> {
+    read(io::stdin().lock())
+}
+
+pub fn read_file(path: &Path) -> Result<String> {
+    let input = fs::read_to_string(path)
+        .map_err(|err| eyre!("failed to read {}: {err}", path.display()))?;
+    Ok(normalize(&input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_endings() {
+        assert_eq!(normalize("a\r\nb\r\n"), "a\nb\n");
+        assert_eq!(normalize("a\rb\r"), "a\nb\n");
+        assert_eq!(normalize("a\nb"), "a\nb\n");
+    }
+
+    #[test]
+    fn bom() {
+        assert_eq!(normalize("\u{feff}a\r\n"), "a\n");
+        assert_eq!(normalize("a\u{feff}\n"), "a\u{feff}\n");
+    }
+
+    #[test]
+    fn blank_lines() {
+        assert_eq!(normalize("a\n\n\n"), "a\n");
+        assert_eq!(normalize("a\r\n \r\n\t\r\n"), "a\n");
+        assert_eq!(normalize("\n\na\n\nb\n"), "\n\na\n\nb\n");
+        assert_eq!(normalize("    [D]    \n"), "    [D]    \n");
+        assert_eq!(normalize("\r\n\r\n"), "");
+        assert_eq!(normalize(""), "");
+    }
+}
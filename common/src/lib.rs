@@ -0,0 +1,58 @@
+// Code shared by the day crates and the tooling that runs them.
+use color_eyre::Result;
+use std::fmt::{self, Display};
+
+#[cfg(feature = "animate")]
+pub mod animate;
+#[cfg(feature = "bench")]
+pub mod bench;
+#[cfg(feature = "generate")]
+pub mod generate;
+#[cfg(feature = "image")]
+pub mod image;
+pub mod input;
+#[cfg(feature = "parse")]
+pub mod parse;
+
+// The entry point for a day's puzzle.  The input is parsed once and both parts are then solved
+// against the parsed state, so that runners, benchmarks and regression checks can treat every day
+// the same way.
+pub trait Solution: Sized {
+    type Part1: Answer;
+    type Part2: Answer;
+
+    fn parse(input: &str) -> Result<Self>;
+
+    fn part1(&self) -> Result<Self::Part1>;
+
+    fn part2(&self) -> Result<Self::Part2>;
+}
+
+// An answer is shown using its Display impl.  Some answers are pictures that have to be read to
+// get what is entered on the puzzle page, such as the letters drawn by the day 10 CRT; these can
+// also be decoded to text.
+pub trait Answer: Display {
+    fn decoded(&self) -> Option<String> {
+        None
+    }
+}
+
+macro_rules! impl_answer {
+    ($($t:ty),+) => {
+        $(impl Answer for $t {})+
+    };
+}
+
+impl_answer!(i16, i32, i64, u16, u64, usize, String);
+
+// The answer for a part that has no puzzle, such as the second part of day 25.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NoAnswer;
+
+impl Display for NoAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "-")
+    }
+}
+
+impl Answer for NoAnswer {}
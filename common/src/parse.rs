@@ -0,0 +1,224 @@
+// Error reporting for the nom parsers.  The parsers use VerboseError, which keeps the context in
+// which a failure happened, and finish turns a failure into a report pointing at the line and
+// column in the puzzle input, with what was expected there:
+//
+//   parse error at line 3, column 5: expected a crate or a hole
+//
+// The report also carries the offending line as an annotated snippet, which is shown when
+// color-eyre is installed:
+//
+//     |
+//   3 | [Z] x [P]
+//     |     ^ expected a crate or a hole
+//     |
+use annotate_snippets::{
+    display_list::{DisplayList, FormatOptions},
+    snippet::{AnnotationType, Slice, Snippet, SourceAnnotation},
+};
+use color_eyre::{Report, Result, Section};
+use nom::{
+    error::{ErrorKind, VerboseError, VerboseErrorKind},
+    Finish,
+};
+use std::fmt::{self, Display};
+
+pub use nom::error::context;
+
+pub type IResult<I, O> = nom::IResult<I, O, VerboseError<I>>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub source_line: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "parse error at line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl ParseError {
+    // The position of rest within input, where rest is what was left to parse when the failure
+    // happened.  Parsers that work one line at a time are fine, as long as the lines they are
+    // given are slices of the whole input.
+    pub fn new(input: &str, rest: &str, expected: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (rest.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(0);
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            source_line: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_owned(),
+        }
+    }
+
+    pub fn snippet(&self) -> String {
+        let label = format!("expected {}", self.expected);
+        let width = self.source_line.chars().count();
+        let column = (self.column - 1).min(width);
+
+        // A space is added so that the end of the line can be pointed at
+        let source = format!("{} ", self.source_line);
+
+        let snippet = Snippet {
+            title: None,
+            footer: vec![],
+            slices: vec![Slice {
+                source: &source,
+                line_start: self.line,
+                origin: None,
+                fold: false,
+                annotations: vec![SourceAnnotation {
+                    range: (column, column + 1),
+                    label: &label,
+                    annotation_type: AnnotationType::Error,
+                }],
+            }],
+            opt: FormatOptions::default(),
+        };
+
+        DisplayList::from(snippet).to_string()
+    }
+}
+
+fn describe(kind: &VerboseErrorKind) -> String {
+    match kind {
+        VerboseErrorKind::Context(context) => context.to_string(),
+        VerboseErrorKind::Char(c) => format!("{c:?}"),
+        VerboseErrorKind::Nom(ErrorKind::Digit) => "a number".to_owned(),
+        VerboseErrorKind::Nom(ErrorKind::Eof) => "the end of the input".to_owned(),
+        VerboseErrorKind::Nom(kind) => kind.description().to_lowercase(),
+    }
+}
+
+impl<'a> From<(&'a str, VerboseError<&'a str>)> for ParseError {
+    // The first error is where the parser gave up.  An expected character or number is the most
+    // precise description of what was wanted there, unless it was only the last of several
+    // alternatives, in which case the innermost context describes them better.
+    fn from((input, err): (&'a str, VerboseError<&'a str>)) -> Self {
+        let Some((rest, first)) = err.errors.first() else {
+            return Self::new(input, input, "valid input");
+        };
+
+        let alternatives = err
+            .errors
+            .iter()
+            .take_while(|(_, kind)| !matches!(kind, VerboseErrorKind::Context(_)))
+            .any(|(_, kind)| matches!(kind, VerboseErrorKind::Nom(ErrorKind::Alt)));
+
+        let specific = !alternatives
+            && matches!(
+                first,
+                VerboseErrorKind::Char(_)
+                    | VerboseErrorKind::Nom(ErrorKind::Digit)
+                    | VerboseErrorKind::Nom(ErrorKind::Eof)
+            );
+
+        let expected = err
+            .errors
+            .iter()
+            .map(|(_, kind)| kind)
+            .find(|kind| !specific && matches!(kind, VerboseErrorKind::Context(_)))
+            .unwrap_or(first);
+
+        Self::new(input, rest, describe(expected))
+    }
+}
+
+pub fn report(err: ParseError) -> Report {
+    let snippet = err.snippet();
+    Report::new(err).section(snippet)
+}
+
+// Finishes a parse of input, or of a line of it, with an error that says where the parse failed
+pub fn finish<'a, O>(input: &'a str, result: IResult<&'a str, O>) -> Result<O> {
+    result
+        .finish()
+        .map(|(_, output)| output)
+        .map_err(|err| report(ParseError::from((input, err))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{
+        bytes::complete::tag,
+        character::complete::u32,
+        combinator::{all_consuming, cut},
+        multi::separated_list1,
+        sequence::preceded,
+    };
+
+    fn numbers(i: &str) -> IResult<&str, Vec<u32>> {
+        all_consuming(separated_list1(
+            tag("\n"),
+            preceded(tag("n: "), cut(context("a number", u32))),
+        ))(i)
+    }
+
+    fn error(input: &str) -> ParseError {
+        let err = finish(input, numbers(input)).unwrap_err();
+        err.downcast_ref::<ParseError>().unwrap().clone()
+    }
+
+    #[test]
+    fn locations() {
+        assert_eq!(finish("n: 1\nn: 2", numbers("n: 1\nn: 2")).unwrap(), [1, 2]);
+
+        let err = error("n: 1\nn: x\nn: 3");
+        assert_eq!((err.line, err.column), (2, 4));
+        assert_eq!(err.expected, "a number");
+        assert_eq!(err.source_line, "n: x");
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 2, column 4: expected a number"
+        );
+
+        let err = error("n: 1\nn: 2 3");
+        assert_eq!((err.line, err.column), (2, 5));
+        assert_eq!(err.source_line, "n: 2 3");
+        assert_eq!(err.expected, "the end of the input");
+    }
+
+    #[test]
+    fn lines() {
+        let input = "n: 1\nm: 2\n";
+        let line = input.lines().nth(1).unwrap();
+        let err = finish(input, numbers(line)).unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn snippets() {
+        let snippet = error("n: 1\nn: x").snippet();
+        assert!(snippet.contains("2 | n: x"));
+        assert!(snippet.contains("^ expected a number"));
+
+        // The end of a line can be pointed at too
+        let input = "n: ";
+        let snippet = ParseError::new(input, &input[3..], "a number").snippet();
+        assert!(snippet.contains("^ expected a number"));
+    }
+}
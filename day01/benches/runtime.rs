@@ -0,0 +1 @@
+common::benchmarks!("day01", include_str!("../data/input.txt"), day01::Runner);
@@ -0,0 +1,24 @@
+// Inputs with `size` elves, each carrying a few snacks
+use common::generate::{Rng, StdRng};
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let snacks = rng.gen_range(1..=10);
+            (0..snacks)
+                .map(|_| format!("{}\n", rng.gen_range(1000..=10_000)))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<crate::Runner>(input, 20, 0..10).unwrap();
+    }
+}
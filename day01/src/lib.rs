@@ -0,0 +1,293 @@
+use color_eyre::{eyre::eyre, Result};
+use common::Solution;
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BTreeMap, BinaryHeap},
+    io::BufRead,
+};
+
+pub mod generate;
+
+// An elf is known by where it comes in the input, counting from 0, so that elves carrying the same
+// number of calories are still told apart.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Elf {
+    pub index: usize,
+    pub calories: u64,
+}
+
+impl Elf {
+    pub fn calories(&self) -> u64 {
+        self.calories
+    }
+}
+
+// More calories come first, and among elves carrying the same the one earlier in the input
+impl Ord for Elf {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.calories
+            .cmp(&other.calories)
+            .then(other.index.cmp(&self.index))
+    }
+}
+
+impl PartialOrd for Elf {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// The elves in an input, read a line at a time so that nothing more than the current line is held
+// in memory.  Any number of blank lines separate one elf from the next, and \r\n line endings are
+// read the same as \n.
+pub struct Elves<R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+    index: usize,
+}
+
+impl<R: BufRead> Elves<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: String::new(),
+            line_number: 0,
+            index: 0,
+        }
+    }
+
+    fn next_elf(&mut self) -> Result<Option<Elf>> {
+        let mut calories = None;
+
+        loop {
+            self.line.clear();
+            if self.reader.read_line(&mut self.line)? == 0 {
+                break;
+            }
+            self.line_number += 1;
+
+            let line = self.line.trim_end_matches(['\n', '\r']);
+            let line = match self.line_number {
+                1 => line.trim_start_matches('\u{feff}'),
+                _ => line,
+            };
+            if line.trim().is_empty() {
+                match calories {
+                    Some(_) => break,
+                    None => continue,
+                }
+            }
+
+            let snack = line
+                .parse::<u64>()
+                .map_err(|err| eyre!("line {}: {err}: {line:?}", self.line_number))?;
+            *calories.get_or_insert(0) += snack;
+        }
+
+        Ok(calories.map(|calories| {
+            self.index += 1;
+            Elf {
+                index: self.index - 1,
+                calories,
+            }
+        }))
+    }
+}
+
+impl<R: BufRead> Iterator for Elves<R> {
+    type Item = Result<Elf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_elf().transpose()
+    }
+}
+
+// The `n` elves carrying the most calories, most first, keeping no more than `n` of them at a time
+pub fn top(elves: impl IntoIterator<Item = Elf>, n: usize) -> Vec<Elf> {
+    let mut heap = BinaryHeap::with_capacity(n + 1);
+
+    for elf in elves {
+        heap.push(Reverse(elf));
+        if heap.len() > n {
+            heap.pop();
+        }
+    }
+
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(elf)| elf)
+        .collect()
+}
+
+// The calories carried by the elves, in order
+pub struct Stats {
+    sorted: Vec<u64>,
+}
+
+impl Stats {
+    pub fn new(calories: impl IntoIterator<Item = u64>) -> Self {
+        let mut sorted = calories.into_iter().collect::<Vec<_>>();
+        sorted.sort_unstable();
+        Self { sorted }
+    }
+
+    pub fn count(&self) -> usize {
+        self.sorted.len()
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        let total = self.sorted.iter().map(|&c| c as u128).sum::<u128>();
+        (!self.sorted.is_empty()).then(|| total as f64 / self.count() as f64)
+    }
+
+    // The middle value, or halfway between the two middle values when there's an even number
+    pub fn median(&self) -> Option<f64> {
+        let n = self.count();
+        match n {
+            0 => None,
+            _ if n % 2 == 1 => Some(self.sorted[n / 2] as f64),
+            _ => Some((self.sorted[n / 2 - 1] as f64 + self.sorted[n / 2] as f64) / 2.0),
+        }
+    }
+
+    // The smallest value that at least `p` percent of the elves carry no more than
+    pub fn percentile(&self, p: f64) -> Option<u64> {
+        if self.sorted.is_empty() || !(0.0..=100.0).contains(&p) {
+            return None;
+        }
+
+        let rank = (p / 100.0 * self.count() as f64).ceil() as usize;
+        Some(self.sorted[rank.saturating_sub(1)])
+    }
+}
+
+// The calories carried by each elf, by index
+pub struct Runner {
+    pub calories: Vec<u64>,
+}
+
+impl Runner {
+    pub fn read(reader: impl BufRead) -> Result<Self> {
+        let calories = Elves::new(reader)
+            .map(|elf| elf.map(|elf| elf.calories))
+            .collect::<Result<_>>()?;
+
+        Ok(Self { calories })
+    }
+
+    pub fn elves(&self) -> impl Iterator<Item = Elf> + '_ {
+        self.calories
+            .iter()
+            .enumerate()
+            .map(|(index, &calories)| Elf { index, calories })
+    }
+
+    pub fn max_calories(&self) -> u64 {
+        self.calories.iter().copied().max().unwrap_or_default()
+    }
+
+    pub fn top(&self, n: usize) -> Vec<Elf> {
+        top(self.elves(), n)
+    }
+
+    pub fn stats(&self) -> Stats {
+        Stats::new(self.calories.iter().copied())
+    }
+
+    // The elves that carry the same number of calories as another elf, most calories first
+    pub fn ties(&self) -> Vec<(u64, Vec<usize>)> {
+        let mut by_calories = BTreeMap::<u64, Vec<usize>>::new();
+        for elf in self.elves() {
+            by_calories.entry(elf.calories).or_default().push(elf.index);
+        }
+
+        by_calories
+            .into_iter()
+            .rev()
+            .filter(|(_, elves)| elves.len() > 1)
+            .collect()
+    }
+}
+
+impl Solution for Runner {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self> {
+        Self::read(input.as_bytes())
+    }
+
+    fn part1(&self) -> Result<u64> {
+        Ok(self.max_calories())
+    }
+
+    fn part2(&self) -> Result<u64> {
+        Ok(self.top(3).iter().map(|e| e.calories()).sum())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn runner(input: &str) -> Runner {
+        Runner::read(input.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn parse_input() {
+        let runner = runner(include_str!("../data/example.txt"));
+
+        assert_eq!(runner.calories, [6000, 4000, 11000, 24000, 10000]);
+        assert_eq!(runner.max_calories(), 24000);
+        assert_eq!(
+            runner.top(3).iter().map(|e| e.calories()).sum::<u64>(),
+            45000
+        );
+    }
+
+    #[test]
+    fn line_endings() {
+        let runner = runner("\u{feff}1000\r\n2000\r\n\r\n\r\n3000\r\n\r\n");
+        assert_eq!(runner.calories, [3000, 3000]);
+
+        let err = Runner::read("1000\n\n20x0\n".as_bytes()).err().unwrap();
+        assert!(err.to_string().starts_with("line 3: "));
+    }
+
+    #[test]
+    fn equal_totals() {
+        let runner = runner("300\n\n100\n\n200\n100\n\n300\n\n50\n");
+        let top = runner.top(3);
+
+        assert_eq!(runner.calories.len(), 5);
+        assert_eq!(
+            top.iter().map(|elf| elf.index).collect::<Vec<_>>(),
+            [0, 2, 3]
+        );
+        assert_eq!(runner.ties(), [(300, vec![0, 2, 3])]);
+        assert_eq!(runner.top(10).len(), 5);
+        assert!(runner.top(0).is_empty());
+    }
+
+    #[test]
+    fn stats() {
+        let stats = runner(include_str!("../data/example.txt")).stats();
+
+        assert_eq!(stats.count(), 5);
+        assert_eq!(stats.mean(), Some(11000.0));
+        assert_eq!(stats.median(), Some(10000.0));
+        assert_eq!(stats.percentile(0.0), Some(4000));
+        assert_eq!(stats.percentile(50.0), Some(10000));
+        assert_eq!(stats.percentile(90.0), Some(24000));
+        assert_eq!(stats.percentile(101.0), None);
+
+        let stats = Stats::new([1, 2, 3, 10]);
+        assert_eq!(stats.median(), Some(2.5));
+        assert_eq!(stats.percentile(25.0), Some(1));
+
+        assert_eq!(Stats::new([]).median(), None);
+        assert_eq!(Stats::new([]).mean(), None);
+    }
+}
@@ -1,127 +1,37 @@
 use color_eyre::{self, Result};
-use std::{
-    collections::BTreeSet,
-    io,
-    iter::{Rev, Take},
-};
-
-#[derive(Debug, Eq, PartialEq, PartialOrd, Ord)]
-struct Elf {
-    pub calories: u64,
-}
-
-impl Elf {
-    fn new() -> Self {
-        Self { calories: 0 }
-    }
-
-    fn calories(&self) -> u64 {
-        self.calories
-    }
-}
-
-struct Runner {
-    _input: Vec<String>,
-    pub elves: BTreeSet<Elf>,
-}
-
-impl Runner {
-    fn parse(input: Vec<String>) -> Result<Self> {
-        let mut elves = BTreeSet::new();
-        let mut current = Some(Elf::new());
-
-        for line in input.iter() {
-            if line.is_empty() {
-                if let Some(elf) = current.take() {
-                    elves.insert(elf);
-                }
-                current = Some(Elf::new());
-                continue;
-            }
-
-            let calories = line.parse::<u64>()?;
-            if let Some(elf) = &mut current {
-                elf.calories += calories;
-            }
-        }
-
-        if let Some(elf) = current.take() {
-            elves.insert(elf);
-        }
-
-        Ok(Self {
-            _input: input,
-            elves,
-        })
-    }
-
-    fn max_calories(&self) -> u64 {
-        self.elves
-            .iter()
-            .map(|e| e.calories())
-            .max()
-            .unwrap_or_default()
-    }
-
-    fn top(&self, n: usize) -> Take<Rev<std::collections::btree_set::Iter<'_, Elf>>> {
-        // BTreeSet implements DoubleEndedIterator, so this should be fast
-        // https://doc.rust-lang.org/std/collections/btree_set/struct.Iter.html
-        // https://stackoverflow.com/a/33699340/61048
-        self.elves.iter().rev().take(n)
-    }
-}
+use day01::Runner;
+use std::io;
 
 fn main() -> Result<()> {
     color_eyre::install()?;
-    let mut lines: Vec<String> = vec![];
-
-    for line in io::stdin().lines() {
-        lines.push(line?);
-    }
 
-    let runner = Runner::parse(lines)?;
+    // The input is read as it comes, rather than through common::input, so that it can be as big
+    // as it likes; Elves copes with \r\n line endings and blank lines itself.
+    let runner = Runner::read(io::stdin().lock())?;
     let max = runner.max_calories();
-    let top_three: u64 = runner.top(3).map(|e| e.calories()).sum();
+    let top_three: u64 = runner.top(3).iter().map(|e| e.calories()).sum();
 
     println!("max calories: {max}");
     println!("sum of top three: {top_three}");
 
-    Ok(())
-}
-
-#[cfg(test)]
-mod test {
-    use super::*;
-    use std::str::FromStr;
-
-    #[test]
-    fn parse_input() {
-        let input: Vec<String> = "
-        1000
-        2000
-        3000
-
-        4000
-
-        5000
-        6000
-
-        7000
-        8000
-        9000
-
-        10000
-        "
-        .trim()
-        .lines()
-        .map(|s| s.trim())
-        .map(|s| String::from_str(s).unwrap())
-        .collect::<Vec<String>>();
-
-        let runner = Runner::parse(input).unwrap();
+    let stats = runner.stats();
+    if let (Some(mean), Some(median)) = (stats.mean(), stats.median()) {
+        println!("elves: {}", stats.count());
+        println!("mean: {mean:.1}, median: {median:.1}");
+        for p in [90.0, 99.0] {
+            println!(
+                "{p}th percentile: {}",
+                stats.percentile(p).unwrap_or_default()
+            );
+        }
+    }
 
-        assert_eq!(runner.elves.len(), 5);
-        assert_eq!(runner.max_calories(), 24000);
-        assert_eq!(runner.top(3).map(|e| e.calories()).sum::<u64>(), 45000);
+    // There can be a great many ties in a big input, so only the biggest are shown
+    let ties = runner.ties();
+    println!("totals shared by more than one elf: {}", ties.len());
+    for (calories, elves) in ties.iter().take(5) {
+        println!("  {calories}: elves {elves:?}");
     }
+
+    Ok(())
 }
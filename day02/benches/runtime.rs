@@ -0,0 +1 @@
+common::benchmarks!("day02", include_str!("../data/input.txt"), day02::Runner);
@@ -0,0 +1,22 @@
+// Strategy guides with `size` rounds
+use common::generate::{SliceRandom, StdRng};
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let theirs = ['A', 'B', 'C'].choose(rng).unwrap();
+            let ours = ['X', 'Y', 'Z'].choose(rng).unwrap();
+            format!("{theirs} {ours}\n")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<crate::Runner>(input, 50, 0..10).unwrap();
+    }
+}
@@ -0,0 +1,300 @@
+use color_eyre::{eyre::eyre, Result};
+use common::Solution;
+use itertools::Itertools;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+pub mod generate;
+pub mod rules;
+
+use rules::{Outcome, Rules, Shape};
+
+// How the strategy guide was read for each part
+pub const THEIRS: &str = "A=rock B=paper C=scissors";
+pub const PART1: &str = "X=rock Y=paper Z=scissors";
+pub const PART2: &str = "X=lose Y=draw Z=win";
+
+// What a symbol in the second column of the strategy guide tells us to do
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Response {
+    Play(Shape),
+    Aim(Outcome),
+}
+
+// How the symbols in each column of the strategy guide are read
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Mapping {
+    pub theirs: HashMap<char, Shape>,
+    pub ours: HashMap<char, Response>,
+}
+
+fn symbols(s: &str) -> impl Iterator<Item = Result<(char, &str)>> {
+    s.split_whitespace().map(|pair| {
+        let (symbol, meaning) = pair
+            .split_once('=')
+            .ok_or_else(|| eyre!("expected SYMBOL=MEANING: {pair}"))?;
+        match symbol.chars().collect_vec()[..] {
+            [symbol] => Ok((symbol, meaning)),
+            _ => Err(eyre!("a symbol is a single character: {symbol}")),
+        }
+    })
+}
+
+impl Mapping {
+    // From lists like "A=rock B=paper C=scissors" for their column and "X=lose Y=draw Z=win" for
+    // ours, where our symbols can stand for either a shape or the outcome to aim for
+    pub fn parse(rules: &Rules, theirs: &str, ours: &str) -> Result<Self> {
+        let shape = |name| {
+            rules
+                .shape(name)
+                .ok_or_else(|| eyre!("unknown shape: {name}"))
+        };
+
+        let theirs = symbols(theirs)
+            .map(|symbol| {
+                let (symbol, name) = symbol?;
+                Ok((symbol, shape(name)?))
+            })
+            .collect::<Result<_>>()?;
+
+        let ours = symbols(ours)
+            .map(|symbol| {
+                let (symbol, meaning) = symbol?;
+                let response = match meaning {
+                    "lose" => Response::Aim(Outcome::Lose),
+                    "draw" => Response::Aim(Outcome::Draw),
+                    "win" => Response::Aim(Outcome::Win),
+                    name => Response::Play(shape(name)?),
+                };
+                Ok((symbol, response))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { theirs, ours })
+    }
+
+    fn round_score(&self, rules: &Rules, (theirs, ours): (char, char)) -> Result<i32> {
+        let theirs = *self
+            .theirs
+            .get(&theirs)
+            .ok_or_else(|| eyre!("no shape for {theirs}"))?;
+
+        let ours = match self.ours.get(&ours) {
+            Some(&Response::Play(shape)) => shape,
+            Some(&Response::Aim(outcome)) => rules.response(theirs, outcome).ok_or_else(|| {
+                let name = rules.name(theirs);
+                eyre!("there's no way to {} against {name}", outcome.name())
+            })?,
+            None => return Err(eyre!("no meaning for {ours}")),
+        };
+
+        Ok(rules.score(ours, theirs))
+    }
+}
+
+// The rounds of a strategy guide, as the symbols in each column
+#[derive(Clone, Debug)]
+pub struct Guide {
+    pub rounds: Vec<(char, char)>,
+}
+
+impl Guide {
+    pub fn parse(input: &str) -> Result<Self> {
+        let rounds = input
+            .lines()
+            .map(str::trim)
+            .enumerate()
+            .map(|(i, line)| match line.chars().collect_vec()[..] {
+                [theirs, ' ', ours] => Ok((theirs, ours)),
+                _ => Err(eyre!("line {}: expected two symbols: {line:?}", i + 1)),
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { rounds })
+    }
+
+    pub fn round_scores(&self, rules: &Rules, mapping: &Mapping) -> Result<Vec<i32>> {
+        self.rounds
+            .iter()
+            .map(|&round| mapping.round_score(rules, round))
+            .collect()
+    }
+
+    pub fn score(&self, rules: &Rules, mapping: &Mapping) -> Result<i32> {
+        self.counts()
+            .into_iter()
+            .map(|(round, count)| Ok(mapping.round_score(rules, round)? * count as i32))
+            .sum()
+    }
+
+    fn counts(&self) -> BTreeMap<(char, char), usize> {
+        self.rounds
+            .iter()
+            .fold(BTreeMap::new(), |mut counts, &round| {
+                *counts.entry(round).or_default() += 1;
+                counts
+            })
+    }
+}
+
+// Our symbols read as shapes or as outcomes to aim for, a different one each, in whichever way
+// scores the most, together with that score.  Shapes come before outcomes, and the first such
+// mapping is chosen when several score the same.  A mapping is passed over if it can't be followed
+// in one of the rounds, as when it aims for an outcome that the rules have no way to get.
+pub fn best_mapping(
+    rules: &Rules,
+    guide: &Guide,
+    theirs: &HashMap<char, Shape>,
+) -> Result<(Mapping, i32)> {
+    let symbols = guide
+        .rounds
+        .iter()
+        .map(|&(_, ours)| ours)
+        .collect::<BTreeSet<_>>();
+    let responses = rules
+        .shapes()
+        .map(Response::Play)
+        .chain(Outcome::ALL.map(Response::Aim));
+    if symbols.len() > responses.clone().count() {
+        return Err(eyre!("more symbols than there are shapes and outcomes"));
+    }
+
+    let mut best: Option<(Mapping, i32)> = None;
+    let mut failed = None;
+    for responses in responses.permutations(symbols.len()) {
+        let mapping = Mapping {
+            theirs: theirs.clone(),
+            ours: symbols.iter().copied().zip(responses).collect(),
+        };
+
+        let score = match guide.score(rules, &mapping) {
+            Ok(score) => score,
+            Err(err) => {
+                failed = Some(err);
+                continue;
+            }
+        };
+        if best.as_ref().map_or(true, |&(_, best)| score > best) {
+            best = Some((mapping, score));
+        }
+    }
+
+    // Only when every mapping fails, in which case the reason the last one did is as good as any
+    match (best, failed) {
+        (Some(best), _) => Ok(best),
+        (None, Some(err)) => Err(err),
+        (None, None) => Err(eyre!("no rounds to play")),
+    }
+}
+
+pub struct Runner {
+    pub part1_scores: Vec<i32>,
+    pub part2_scores: Vec<i32>,
+}
+
+impl Runner {
+    pub fn parse(input: &str) -> Result<Self> {
+        let rules = Rules::rock_paper_scissors();
+        let guide = Guide::parse(input)?;
+        let scores = |ours| guide.round_scores(&rules, &Mapping::parse(&rules, THEIRS, ours)?);
+
+        Ok(Self {
+            part1_scores: scores(PART1)?,
+            part2_scores: scores(PART2)?,
+        })
+    }
+
+    pub fn part1_total(&self) -> i32 {
+        self.part1_scores.iter().sum()
+    }
+
+    pub fn part2_total(&self) -> i32 {
+        self.part2_scores.iter().sum()
+    }
+}
+
+impl Solution for Runner {
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self> {
+        Self::parse(input)
+    }
+
+    fn part1(&self) -> Result<i32> {
+        Ok(self.part1_total())
+    }
+
+    fn part2(&self) -> Result<i32> {
+        Ok(self.part2_total())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_input() {
+        let input = "A Y
+        B X
+        C Z";
+
+        let runner = Runner::parse(input).unwrap();
+        assert_eq!(runner.part1_scores.len(), 3);
+        assert_eq!(runner.part1_scores[0], 8);
+        assert_eq!(runner.part1_scores[1], 1);
+        assert_eq!(runner.part1_scores[2], 6);
+
+        assert_eq!(runner.part1_total(), 15);
+        assert_eq!(runner.part2_total(), 12);
+    }
+
+    #[test]
+    fn bad_input() {
+        assert!(Runner::parse("A Y\nB\n").is_err());
+        assert!(Runner::parse("A Y\nD X\n").is_err());
+        assert!(Runner::parse("A W\n").is_err());
+    }
+
+    #[test]
+    fn mappings() {
+        let rules = Rules::rock_paper_scissors_lizard_spock();
+        let guide = Guide::parse("A V\nD W\nE X\n").unwrap();
+        let mapping = Mapping::parse(
+            &rules,
+            "A=rock B=paper C=scissors D=lizard E=spock",
+            "V=spock W=win X=draw",
+        )
+        .unwrap();
+
+        // Spock beats rock, lizard loses to both rock and scissors but scissors scores more, and a
+        // draw with spock needs spock
+        assert_eq!(guide.round_scores(&rules, &mapping).unwrap(), [11, 9, 8]);
+        assert_eq!(guide.score(&rules, &mapping).unwrap(), 28);
+
+        assert!(Mapping::parse(&rules, "A=stone", "").is_err());
+        assert!(Mapping::parse(&rules, "AB=rock", "").is_err());
+        assert!(Mapping::parse(&rules, "A rock", "").is_err());
+    }
+
+    #[test]
+    fn best() {
+        let rules = Rules::rock_paper_scissors();
+        let guide = Guide::parse("A Y\nB X\nC Z\n").unwrap();
+        let theirs = Mapping::parse(&rules, THEIRS, "").unwrap().theirs;
+        let (mapping, score) = best_mapping(&rules, &guide, &theirs).unwrap();
+
+        // Winning every round
+        assert_eq!(score, 8 + 9 + 7);
+        let shape = |name| Response::Play(rules.shape(name).unwrap());
+        assert_eq!(mapping.ours[&'X'], shape("scissors"));
+        assert_eq!(mapping.ours[&'Y'], shape("paper"));
+        assert_eq!(mapping.ours[&'Z'], shape("rock"));
+
+        // No one shape wins against all of theirs, but aiming to win does
+        let guide = Guide::parse("A X\nB X\nC X\n").unwrap();
+        let (mapping, score) = best_mapping(&rules, &guide, &theirs).unwrap();
+        assert_eq!(score, 8 + 9 + 7);
+        assert_eq!(mapping.ours[&'X'], Response::Aim(Outcome::Win));
+    }
+}
@@ -0,0 +1,194 @@
+// The rules of a game like rock paper scissors as data: the shapes that can be played and what each
+// scores, which shapes beat which, and what a round scores for each outcome.  Two shapes where
+// neither beats the other make a draw.
+use color_eyre::{eyre::eyre, Result};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Self; 3] = [Self::Lose, Self::Draw, Self::Win];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Lose => "lose",
+            Self::Draw => "draw",
+            Self::Win => "win",
+        }
+    }
+}
+
+// A shape, by where it comes in the rules
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Shape(pub usize);
+
+#[derive(Clone, Debug)]
+pub struct Rules {
+    names: Vec<String>,
+    scores: Vec<i32>,
+    // Whether the first shape beats the second
+    beats: Vec<Vec<bool>>,
+    // By outcome, for losing, drawing and winning
+    outcome_scores: [i32; 3],
+}
+
+impl Rules {
+    pub fn new(shapes: &[(&str, i32)], beats: &[(&str, &str)]) -> Result<Self> {
+        let names = shapes
+            .iter()
+            .map(|(name, _)| name.to_lowercase())
+            .collect::<Vec<_>>();
+        for (i, name) in names.iter().enumerate() {
+            if names[..i].contains(name) {
+                return Err(eyre!("more than one shape called {name}"));
+            }
+        }
+
+        let mut rules = Self {
+            scores: shapes.iter().map(|&(_, score)| score).collect(),
+            beats: vec![vec![false; names.len()]; names.len()],
+            names,
+            outcome_scores: [0, 3, 6],
+        };
+
+        for &(winner, loser) in beats {
+            let shape = |name| {
+                rules
+                    .shape(name)
+                    .ok_or_else(|| eyre!("unknown shape: {name}"))
+            };
+            let (Shape(winner), Shape(loser)) = (shape(winner)?, shape(loser)?);
+
+            if winner == loser || rules.beats[loser][winner] {
+                let (winner, loser) = (&rules.names[winner], &rules.names[loser]);
+                return Err(eyre!("{winner} can't beat {loser} and lose to it too"));
+            }
+            rules.beats[winner][loser] = true;
+        }
+
+        Ok(rules)
+    }
+
+    pub fn with_outcome_scores(self, lose: i32, draw: i32, win: i32) -> Self {
+        Self {
+            outcome_scores: [lose, draw, win],
+            ..self
+        }
+    }
+
+    pub fn rock_paper_scissors() -> Self {
+        Self::new(
+            &[("rock", 1), ("paper", 2), ("scissors", 3)],
+            &[
+                ("rock", "scissors"),
+                ("paper", "rock"),
+                ("scissors", "paper"),
+            ],
+        )
+        .unwrap()
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Self::new(
+            &[
+                ("rock", 1),
+                ("paper", 2),
+                ("scissors", 3),
+                ("lizard", 4),
+                ("spock", 5),
+            ],
+            &[
+                ("scissors", "paper"),
+                ("paper", "rock"),
+                ("rock", "lizard"),
+                ("lizard", "spock"),
+                ("spock", "scissors"),
+                ("scissors", "lizard"),
+                ("lizard", "paper"),
+                ("paper", "spock"),
+                ("spock", "rock"),
+                ("rock", "scissors"),
+            ],
+        )
+        .unwrap()
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> + Clone {
+        (0..self.names.len()).map(Shape)
+    }
+
+    // Shapes are named without regard to case
+    pub fn shape(&self, name: &str) -> Option<Shape> {
+        let name = name.to_lowercase();
+        self.names.iter().position(|n| *n == name).map(Shape)
+    }
+
+    pub fn name(&self, Shape(shape): Shape) -> &str {
+        &self.names[shape]
+    }
+
+    pub fn outcome(&self, Shape(ours): Shape, Shape(theirs): Shape) -> Outcome {
+        match (self.beats[ours][theirs], self.beats[theirs][ours]) {
+            (true, _) => Outcome::Win,
+            (_, true) => Outcome::Lose,
+            _ => Outcome::Draw,
+        }
+    }
+
+    pub fn score(&self, ours: Shape, theirs: Shape) -> i32 {
+        self.scores[ours.0] + self.outcome_scores[self.outcome(ours, theirs) as usize]
+    }
+
+    // The shape to play for an outcome, the best scoring one if there's a choice
+    pub fn response(&self, theirs: Shape, outcome: Outcome) -> Option<Shape> {
+        self.shapes()
+            .filter(|&ours| self.outcome(ours, theirs) == outcome)
+            .max_by_key(|&ours| (self.scores[ours.0], std::cmp::Reverse(ours)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rock_paper_scissors() {
+        let rules = Rules::rock_paper_scissors();
+        let [rock, paper, scissors] =
+            ["rock", "Paper", "SCISSORS"].map(|s| rules.shape(s).unwrap());
+
+        assert_eq!(rules.outcome(paper, rock), Outcome::Win);
+        assert_eq!(rules.outcome(scissors, rock), Outcome::Lose);
+        assert_eq!(rules.outcome(rock, rock), Outcome::Draw);
+        assert_eq!(rules.score(paper, rock), 8);
+        assert_eq!(rules.response(rock, Outcome::Lose), Some(scissors));
+    }
+
+    #[test]
+    fn lizard_spock() {
+        let rules = Rules::rock_paper_scissors_lizard_spock().with_outcome_scores(-1, 0, 1);
+        let [rock, paper, spock] = ["rock", "paper", "spock"].map(|s| rules.shape(s).unwrap());
+
+        assert!(rules.shapes().all(|ours| rules
+            .shapes()
+            .filter(|&theirs| rules.outcome(ours, theirs) == Outcome::Win)
+            .count()
+            == 2));
+        assert_eq!(rules.outcome(spock, rock), Outcome::Win);
+        assert_eq!(rules.score(rock, paper), 0);
+        assert_eq!(rules.response(rock, Outcome::Win), Some(spock));
+        assert_eq!(rules.response(rock, Outcome::Draw), Some(rock));
+    }
+
+    #[test]
+    fn bad_rules() {
+        assert!(Rules::new(&[("rock", 1), ("Rock", 2)], &[]).is_err());
+        assert!(Rules::new(&[("rock", 1)], &[("rock", "paper")]).is_err());
+        assert!(Rules::new(&[("rock", 1)], &[("rock", "rock")]).is_err());
+        assert!(Rules::new(&[("a", 1), ("b", 2)], &[("a", "b"), ("b", "a")]).is_err());
+    }
+}
@@ -0,0 +1,5 @@
+common::benchmarks!(
+    "day03",
+    include_str!("../data/input.txt"),
+    day03::Calculations
+);
@@ -0,0 +1,64 @@
+// Inputs with `size` groups of three rucksacks.  The items of a group are split between its three
+// elves so that the badge is the only item they all carry, and the items of a rucksack are split
+// between its two compartments so that exactly one item is in both.
+use common::generate::{Rng, SliceRandom, StdRng};
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn rucksack(rng: &mut StdRng, pool: &[u8], badge: u8) -> String {
+    let mut pool = pool.to_vec();
+    pool.shuffle(rng);
+
+    // The item in both compartments, and the items that are only in one or the other
+    let shared = *pool.choose(rng).unwrap();
+    let others = pool.iter().copied().filter(|&item| item != shared);
+    let (left, right): (Vec<_>, Vec<_>) = others.partition(|_| rng.gen_bool(0.5));
+    let (left, right) = (
+        if left.is_empty() { vec![shared] } else { left },
+        if right.is_empty() {
+            vec![shared]
+        } else {
+            right
+        },
+    );
+
+    let len = rng.gen_range(2..=16);
+    let mut compartment = |items: &[u8], first: &[u8]| {
+        let mut compartment = first.to_vec();
+        compartment.extend((first.len()..len).map(|_| *items.choose(rng).unwrap()));
+        compartment.shuffle(rng);
+        compartment
+    };
+
+    let mut left = compartment(&left, &[shared, badge]);
+    let right = compartment(&right, &[shared]);
+    left.extend(right);
+    String::from_utf8(left).unwrap()
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    (0..size.max(1))
+        .flat_map(|_| {
+            let mut items = ITEMS.to_vec();
+            items.shuffle(rng);
+            let badge = items.pop().unwrap();
+
+            // Each elf has a third of the other items to themselves
+            let pools = items.chunks(items.len() / 3 + 1).map(<[u8]>::to_vec);
+            pools
+                .map(|pool| rucksack(rng, &pool, badge))
+                .collect::<Vec<_>>()
+        })
+        .map(|rucksack| rucksack + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<crate::Calculations>(input, 20, 0..10).unwrap();
+    }
+}
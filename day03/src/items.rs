@@ -0,0 +1,152 @@
+// A set of items as a bit for each, in order of priority: a to z are bits 0 to 25 and A to Z bits
+// 26 to 51.  Sets of items are intersected and combined with a single & or |.
+use crate::priority;
+use color_eyre::{eyre::eyre, Report, Result};
+use std::{
+    fmt::Debug,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign},
+    str::FromStr,
+};
+
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct Items(u64);
+
+// The item with a priority
+pub fn item(priority: i32) -> Option<char> {
+    match priority {
+        1..=26 => Some((b'a' + priority as u8 - 1) as char),
+        27..=52 => Some((b'A' + priority as u8 - 27) as char),
+        _ => None,
+    }
+}
+
+impl Items {
+    pub const EMPTY: Self = Self(0);
+    pub const ALL: Self = Self((1 << 52) - 1);
+
+    pub fn insert(&mut self, item: char) -> Result<()> {
+        self.0 |= 1 << (priority(item)? - 1);
+        Ok(())
+    }
+
+    pub fn contains(&self, item: char) -> bool {
+        priority(item).map_or(false, |priority| self.0 >> (priority - 1) & 1 == 1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    // The items in order of priority
+    pub fn iter(&self) -> impl Iterator<Item = char> {
+        let mut bits = self.0;
+        std::iter::from_fn(move || {
+            let bit = bits.trailing_zeros();
+            bits &= bits.wrapping_sub(1);
+            (bit < 64).then(|| item(bit as i32 + 1).unwrap())
+        })
+    }
+
+    pub fn with_priorities(&self) -> impl Iterator<Item = (char, i32)> {
+        self.iter().map(|item| (item, priority(item).unwrap()))
+    }
+}
+
+impl FromStr for Items {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut items = Self::EMPTY;
+        for c in s.chars() {
+            items.insert(c).map_err(|_| eyre!("not an item: {c:?}"))?;
+        }
+        Ok(items)
+    }
+}
+
+impl FromIterator<char> for Items {
+    // Anything that isn't an item is left out
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut items = Self::EMPTY;
+        for c in iter {
+            let _ = items.insert(c);
+        }
+        items
+    }
+}
+
+impl Debug for Items {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl BitAnd for Items {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl BitAndAssign for Items {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0;
+    }
+}
+
+impl BitOr for Items {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Items {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sets() {
+        let left = "vJrwpWtwJgWr".parse::<Items>().unwrap();
+        let right = "hcsFMMfFFhFp".parse::<Items>().unwrap();
+
+        assert_eq!(left.len(), 8);
+        assert!(left.contains('J') && !left.contains('j') && !left.contains('1'));
+        assert_eq!((left & right).iter().collect::<String>(), "p");
+        assert_eq!((left | right).len(), 14);
+        assert_eq!(
+            format!("{:?}", "Zaz".parse::<Items>().unwrap()),
+            "{'a', 'z', 'Z'}"
+        );
+        assert_eq!(Items::ALL.len(), 52);
+        assert_eq!(Items::ALL.iter().last(), Some('Z'));
+        assert!("ab1".parse::<Items>().is_err());
+    }
+
+    #[test]
+    fn priorities() {
+        for priority in 1..=52 {
+            assert_eq!(crate::priority(item(priority).unwrap()).unwrap(), priority);
+        }
+        assert_eq!(item(0), None);
+        assert_eq!(item(53), None);
+
+        let items = "aZ".parse::<Items>().unwrap();
+        assert_eq!(
+            items.with_priorities().collect::<Vec<_>>(),
+            [('a', 1), ('Z', 52)]
+        );
+    }
+}
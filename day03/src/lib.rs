@@ -0,0 +1,264 @@
+use color_eyre::{eyre::eyre, Report, Result};
+use common::Solution;
+use std::str::FromStr;
+
+pub mod generate;
+pub mod items;
+
+pub use items::Items;
+
+pub fn priority(c: char) -> Result<i32> {
+    match c {
+        'a'..='z' => Ok(c as i32 - 'a' as i32 + 1),
+        'A'..='Z' => Ok(c as i32 - 'A' as i32 + 26 + 1),
+        _ => Err(eyre!("unexpected value")),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Rucksack {
+    pub left: Items,
+    pub right: Items,
+    // The item in both compartments with the lowest priority
+    pub shared_item: char,
+}
+
+impl Rucksack {
+    pub fn items(&self) -> Items {
+        self.left | self.right
+    }
+
+    pub fn shared(&self) -> Items {
+        self.left & self.right
+    }
+}
+
+impl FromStr for Rucksack {
+    type Err = Report;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let s = s.trim();
+        let len = s.len();
+
+        if len % 2 != 0 {
+            return Err(eyre!("an even sized string is required"));
+        }
+
+        // Checked before splitting, so that the split can't land inside a character
+        s.parse::<Items>()?;
+        let len = len / 2;
+        let (left, right) = (s[..len].parse::<Items>()?, s[len..].parse::<Items>()?);
+
+        match (left & right).iter().next() {
+            Some(shared_item) => Ok(Self {
+                left,
+                right,
+                shared_item,
+            }),
+            None => Err(eyre!("no item found more than once")),
+        }
+    }
+}
+
+pub fn rucksacks(input: &str) -> Result<Vec<Rucksack>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            line.parse()
+                .map_err(|err| eyre!("line {}: {err}: {line:?}", i + 1))
+        })
+        .collect()
+}
+
+// The ways of putting rucksacks into groups of `n`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Partition {
+    // Each run of n rucksacks, one after the other, as the elves are grouped in the puzzle
+    Chunks(usize),
+    // The rucksacks dealt out in turn, so that a group has every so many rucksacks through the list
+    Interleaved(usize),
+    // Every run of n rucksacks, overlapping
+    Windows(usize),
+}
+
+// The rucksacks in a group, by their position in the list, and the items they all carry
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Group {
+    pub members: Vec<usize>,
+    pub shared: Items,
+}
+
+impl Group {
+    fn new(rucksacks: &[Rucksack], members: Vec<usize>) -> Self {
+        let shared = members
+            .iter()
+            .fold(Items::ALL, |shared, &i| shared & rucksacks[i].items());
+
+        Self { members, shared }
+    }
+
+    // The item carried by the whole group with the lowest priority
+    pub fn badge(&self) -> Result<char> {
+        self.shared
+            .iter()
+            .next()
+            .ok_or_else(|| eyre!("no badge found for {:?}", self.members))
+    }
+
+    pub fn priority(&self) -> Result<i32> {
+        priority(self.badge()?)
+    }
+}
+
+pub fn groups(rucksacks: &[Rucksack], partition: Partition) -> Result<Vec<Group>> {
+    let len = rucksacks.len();
+    let (Partition::Chunks(n) | Partition::Interleaved(n) | Partition::Windows(n)) = partition;
+
+    if n == 0 {
+        return Err(eyre!("a group needs at least one rucksack"));
+    }
+    if len % n != 0 && !matches!(partition, Partition::Windows(_)) {
+        return Err(eyre!("{len} rucksacks can't be split into groups of {n}"));
+    }
+
+    let members = |group: usize| -> Vec<usize> {
+        match partition {
+            Partition::Chunks(_) => (group * n..(group + 1) * n).collect(),
+            Partition::Interleaved(_) => (group..len).step_by(len / n).collect(),
+            Partition::Windows(_) => (group..group + n).collect(),
+        }
+    };
+    let count = match partition {
+        Partition::Chunks(_) | Partition::Interleaved(_) => len / n,
+        Partition::Windows(_) => (len + 1).saturating_sub(n),
+    };
+
+    Ok((0..count)
+        .map(|group| Group::new(rucksacks, members(group)))
+        .collect())
+}
+
+pub struct Calculations {
+    pub part1: i32,
+    pub part2: i32,
+}
+
+impl Calculations {
+    pub fn new(rucksacks: &[Rucksack]) -> Result<Self> {
+        let part1 = rucksacks
+            .iter()
+            .map(|rucksack| priority(rucksack.shared_item))
+            .sum::<Result<_>>()?;
+        let part2 = groups(rucksacks, Partition::Chunks(3))?
+            .iter()
+            .map(Group::priority)
+            .sum::<Result<_>>()?;
+
+        Ok(Calculations { part1, part2 })
+    }
+}
+
+impl Solution for Calculations {
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self> {
+        Self::new(&rucksacks(input)?)
+    }
+
+    fn part1(&self) -> Result<i32> {
+        Ok(self.part1)
+    }
+
+    fn part2(&self) -> Result<i32> {
+        Ok(self.part2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+        vJrwpWtwJgWrhcsFMMfFFhFp
+        jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+        PmmdzqPrVvPwwTWBwg
+        wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+        ttgJtRGJQctTZtZT
+        CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn priority_value() {
+        assert_eq!(priority('a').unwrap(), 1);
+        assert_eq!(priority('z').unwrap(), 26);
+        assert_eq!(priority('A').unwrap(), 27);
+        assert_eq!(priority('Z').unwrap(), 52);
+    }
+
+    #[test]
+    fn rucksack_from_str() {
+        let actual = EXAMPLE
+            .lines()
+            .map(|s| s.parse::<Rucksack>().unwrap().shared_item)
+            .collect::<Vec<char>>();
+        let expected = ['p', 'L', 'P', 'v', 't', 's'];
+
+        assert_eq!(actual, expected);
+        assert!("abc".parse::<Rucksack>().is_err());
+        assert!("abcd".parse::<Rucksack>().is_err());
+        assert!("aéa".parse::<Rucksack>().is_err());
+    }
+
+    #[test]
+    fn group_from_rucksacs() {
+        let rucksacks = rucksacks(EXAMPLE).unwrap();
+        let groups = groups(&rucksacks, Partition::Chunks(3)).unwrap();
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].members, [0, 1, 2]);
+        assert_eq!(groups[0].priority().unwrap(), 18);
+        assert_eq!(groups[1].priority().unwrap(), 52);
+    }
+
+    #[test]
+    fn partitions() {
+        let rucksacks = rucksacks(EXAMPLE).unwrap();
+        let members = |partition| {
+            groups(&rucksacks, partition)
+                .unwrap()
+                .into_iter()
+                .map(|group| group.members)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(members(Partition::Chunks(2)), [[0, 1], [2, 3], [4, 5]]);
+        assert_eq!(members(Partition::Interleaved(2)), [[0, 3], [1, 4], [2, 5]]);
+        assert_eq!(members(Partition::Interleaved(3)), [[0, 2, 4], [1, 3, 5]]);
+        assert_eq!(
+            members(Partition::Windows(5)),
+            [[0, 1, 2, 3, 4], [1, 2, 3, 4, 5]]
+        );
+        assert_eq!(members(Partition::Windows(7)), Vec::<Vec<usize>>::new());
+        assert_eq!(members(Partition::Chunks(1)).len(), 6);
+
+        assert!(groups(&rucksacks, Partition::Chunks(4)).is_err());
+        assert!(groups(&rucksacks, Partition::Windows(0)).is_err());
+    }
+
+    #[test]
+    fn shared_items() {
+        let rucksacks = rucksacks(EXAMPLE).unwrap();
+        let groups = groups(&rucksacks, Partition::Chunks(2)).unwrap();
+        let shared = groups
+            .iter()
+            .map(|group| group.shared.with_priorities().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            shared[1],
+            [('q', 17), ('v', 22), ('w', 23), ('B', 28), ('T', 46)]
+        );
+        assert_eq!(groups[2].badge().unwrap(), 'G');
+    }
+}
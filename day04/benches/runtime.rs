@@ -0,0 +1 @@
+common::benchmarks!("day04", include_str!("../data/input.txt"), day04::Task);
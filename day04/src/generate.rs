@@ -0,0 +1,24 @@
+// Inputs with `size` pairs of section assignments
+use common::generate::{Rng, StdRng};
+
+fn assignment(rng: &mut StdRng) -> String {
+    let start = rng.gen_range(1..=99);
+    let end = rng.gen_range(start..=99);
+    format!("{start}-{end}")
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{},{}\n", assignment(rng), assignment(rng)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<crate::Task>(input, 50, 0..10).unwrap();
+    }
+}
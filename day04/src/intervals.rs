@@ -0,0 +1,229 @@
+// Sets of sections for looking at a whole camp's assignments at once, rather than a pair at a time.
+// Sections are whole numbers, so ranges that touch end to end, like 2-4 and 5-6, merge into one.
+use crate::ElfRange;
+use std::{collections::BTreeMap, fmt, ops::RangeInclusive};
+
+// Disjoint ranges of sections, in order, with a gap of at least one section between each
+#[derive(Clone, Default, Eq, PartialEq)]
+pub struct IntervalSet {
+    ranges: Vec<RangeInclusive<u32>>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<u32>) {
+        if range.is_empty() {
+            return;
+        }
+
+        // The ranges that overlap or touch the new one are all merged into it
+        let touches = |other: &RangeInclusive<u32>| {
+            let grown = other.start().saturating_sub(1)..=other.end().saturating_add(1);
+            grown.overlaps(&range)
+        };
+        let first = self
+            .ranges
+            .partition_point(|r| r.end() < range.start() && !touches(r));
+        let last = first + self.ranges[first..].partition_point(touches);
+
+        let merged = self.ranges[first..last].iter().fold(range, |merged, r| {
+            *merged.start().min(r.start())..=*merged.end().max(r.end())
+        });
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn ranges(&self) -> &[RangeInclusive<u32>] {
+        &self.ranges
+    }
+
+    pub fn contains(&self, section: u32) -> bool {
+        let i = self.ranges.partition_point(|r| *r.end() < section);
+        self.ranges.get(i).map_or(false, |r| r.contains(&section))
+    }
+
+    // Whether every section of the range is in the set
+    pub fn covers(&self, range: &RangeInclusive<u32>) -> bool {
+        range.is_empty() || self.ranges.iter().any(|r| r.superset(range))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // The number of sections, which can be one more than a u32 holds
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|r| *r.end() as u64 - *r.start() as u64 + 1)
+            .sum()
+    }
+
+    // The sections within `bounds` that aren't in the set
+    pub fn gaps(&self, bounds: RangeInclusive<u32>) -> Self {
+        let mut gaps = Self::new();
+        if bounds.is_empty() {
+            return gaps;
+        }
+
+        let mut next = Some(*bounds.start());
+        for r in self.ranges.iter().filter(|r| r.overlaps(&bounds)) {
+            if let Some(start) = next.filter(|start| start < r.start()) {
+                gaps.ranges.push(start..=r.start() - 1);
+            }
+            next = r.end().checked_add(1);
+        }
+        if let Some(start) = next.filter(|start| start <= bounds.end()) {
+            gaps.ranges.push(start..=*bounds.end());
+        }
+
+        gaps
+    }
+}
+
+impl FromIterator<RangeInclusive<u32>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<u32>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+impl fmt::Debug for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.ranges.iter()).finish()
+    }
+}
+
+// How many assignments cover each section, as the sections where the count changes and the count
+// from there up to the next change.  The count is 0 before the first change and after the last.
+#[derive(Clone, Debug, Default)]
+pub struct Coverage {
+    steps: Vec<(u64, usize)>,
+}
+
+impl Coverage {
+    pub fn new(assignments: impl IntoIterator<Item = RangeInclusive<u32>>) -> Self {
+        let mut changes = BTreeMap::<u64, isize>::new();
+        for range in assignments.into_iter().filter(|r| !r.is_empty()) {
+            *changes.entry(*range.start() as u64).or_default() += 1;
+            *changes.entry(*range.end() as u64 + 1).or_default() -= 1;
+        }
+
+        let mut count = 0;
+        let mut steps = Vec::<(u64, usize)>::new();
+        for (section, change) in changes {
+            count += change;
+            match steps.last_mut() {
+                Some(last) if last.1 == count as usize => {}
+                _ => steps.push((section, count as usize)),
+            }
+        }
+
+        Self { steps }
+    }
+
+    // The number of assignments that include a section
+    pub fn depth(&self, section: u32) -> usize {
+        let i = self.steps.partition_point(|&(s, _)| s <= section as u64);
+        i.checked_sub(1).map_or(0, |i| self.steps[i].1)
+    }
+
+    // The sections from the first assigned to the last
+    pub fn span(&self) -> Option<RangeInclusive<u32>> {
+        match (self.steps.first(), self.steps.last()) {
+            (Some(&(first, _)), Some(&(last, _))) => Some(first as u32..=(last - 1) as u32),
+            _ => None,
+        }
+    }
+
+    fn runs(&self) -> impl DoubleEndedIterator<Item = (RangeInclusive<u32>, usize)> + '_ {
+        self.steps
+            .windows(2)
+            .map(|w| (w[0].0 as u32..=(w[1].0 - 1) as u32, w[0].1))
+    }
+
+    // The sections covered by at least `k` assignments
+    pub fn at_least(&self, k: usize) -> IntervalSet {
+        self.runs()
+            .filter(|&(_, count)| count >= k.max(1))
+            .map(|(range, _)| range)
+            .collect()
+    }
+
+    // The sections between the first and last assigned that nobody is assigned to
+    pub fn uncovered(&self) -> IntervalSet {
+        match self.span() {
+            Some(span) => self.at_least(1).gaps(span),
+            None => IntervalSet::new(),
+        }
+    }
+
+    // The first section covered by the most assignments, and how many that is
+    pub fn max_overlap(&self) -> Option<(u32, usize)> {
+        self.runs()
+            .map(|(range, count)| (*range.start(), count))
+            .rev()
+            .max_by_key(|&(_, count)| count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merges() {
+        let set = [
+            5..=7,
+            2..=3,
+            10..=12,
+            4..=4,
+            11..=15,
+            RangeInclusive::new(20, 19),
+        ]
+        .into_iter()
+        .collect::<IntervalSet>();
+
+        assert_eq!(set.ranges(), [2..=7, 10..=15]);
+        assert_eq!(set.len(), 12);
+        assert!(set.contains(7) && !set.contains(8) && set.contains(10));
+        assert!(set.covers(&(3..=6)) && !set.covers(&(6..=10)));
+        assert_eq!(set.gaps(0..=20).ranges(), [0..=1, 8..=9, 16..=20]);
+        assert_eq!(set.gaps(3..=12).ranges(), [8..=9]);
+
+        let mut set = set;
+        set.insert(8..=9);
+        assert_eq!(set.ranges(), [2..=15]);
+        set.insert(0..=u32::MAX);
+        assert_eq!(set.len(), 1 << 32);
+        assert!(set.gaps(0..=u32::MAX).is_empty());
+    }
+
+    #[test]
+    fn coverage() {
+        let coverage = Coverage::new([2..=4, 6..=8, 2..=3, 4..=5, 5..=7, 7..=9, 2..=8, 3..=7]);
+
+        assert_eq!(coverage.span(), Some(2..=9));
+        assert_eq!(coverage.depth(1), 0);
+        assert_eq!(coverage.depth(7), 5);
+        assert_eq!(coverage.depth(10), 0);
+        assert_eq!(coverage.at_least(1).ranges(), [2..=9]);
+        assert_eq!(coverage.at_least(4).ranges(), [3..=7]);
+        assert_eq!(coverage.at_least(5).ranges(), [7..=7]);
+        assert!(coverage.at_least(6).is_empty());
+        assert!(coverage.uncovered().is_empty());
+        assert_eq!(coverage.max_overlap(), Some((7, 5)));
+
+        let coverage = Coverage::new([1..=2, 6..=6, 8..=9, 1..=1]);
+        assert_eq!(coverage.uncovered().ranges(), [3..=5, 7..=7]);
+        assert_eq!(coverage.max_overlap(), Some((1, 2)));
+
+        assert_eq!(Coverage::new([]).max_overlap(), None);
+        assert_eq!(Coverage::new([u32::MAX..=u32::MAX]).depth(u32::MAX), 1);
+    }
+}
@@ -0,0 +1,180 @@
+use std::str::FromStr;
+
+use color_eyre::{Report, Result};
+use common::Solution;
+
+use color_eyre::eyre::eyre;
+use itertools::Itertools;
+use std::ops;
+
+pub mod generate;
+pub mod intervals;
+
+use intervals::Coverage;
+
+trait ElfRange {
+    fn superset(&self, other: &Self) -> bool;
+
+    fn contains_or_is_contained(&self, other: &Self) -> bool;
+
+    fn overlaps(&self, other: &Self) -> bool;
+}
+
+impl ElfRange for ops::RangeInclusive<u32> {
+    fn superset(&self, other: &Self) -> bool {
+        self.contains(other.start()) && self.contains(other.end())
+    }
+
+    fn contains_or_is_contained(&self, other: &Self) -> bool {
+        self.superset(other) || other.superset(self)
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        self.contains(other.start()) || other.contains(self.start())
+    }
+}
+
+struct RangeIn(ops::RangeInclusive<u32>);
+
+impl FromStr for RangeIn {
+    type Err = Report;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if let Some((s, e)) = s.split('-').tuples().next() {
+            let s: u32 = s.parse()?;
+            let e: u32 = e.parse()?;
+            return Ok(Self(s..=e));
+        }
+
+        Err(eyre!("bad input"))
+    }
+}
+
+impl RangeIn {
+    fn contains_or_is_contained(&self, other: &Self) -> bool {
+        self.0.contains_or_is_contained(&other.0)
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        self.0.overlaps(&other.0)
+    }
+}
+
+pub struct Pair(RangeIn, RangeIn);
+
+impl FromStr for Pair {
+    type Err = Report;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if let Some((l, r)) = s.trim().split(',').collect_tuple::<(_, _)>() {
+            let l: RangeIn = l.parse()?;
+            let r: RangeIn = r.parse()?;
+            return Ok(Self(l, r));
+        }
+
+        Err(eyre!("bad input"))
+    }
+}
+
+impl Pair {
+    pub fn supersets(&self) -> bool {
+        self.0.contains_or_is_contained(&self.1)
+    }
+
+    pub fn overlaps(&self) -> bool {
+        self.0.overlaps(&self.1)
+    }
+
+    pub fn ranges(&self) -> [ops::RangeInclusive<u32>; 2] {
+        [self.0 .0.clone(), self.1 .0.clone()]
+    }
+}
+
+pub struct Task(Vec<Pair>);
+
+impl Task {
+    pub fn parse(input: &str) -> Result<Self> {
+        let pairs = input
+            .lines()
+            .map(|l| l.parse::<Pair>())
+            .collect::<Result<Vec<Pair>>>()?;
+        Ok(Self(pairs))
+    }
+
+    pub fn supersets(&self) -> usize {
+        self.0.iter().filter(|pair| pair.supersets()).count()
+    }
+
+    pub fn overlaps(&self) -> usize {
+        self.0.iter().filter(|pair| pair.overlaps()).count()
+    }
+
+    // Every elf's assignment across the camp, both of each pair
+    pub fn assignments(&self) -> impl Iterator<Item = ops::RangeInclusive<u32>> + '_ {
+        self.0.iter().flat_map(Pair::ranges)
+    }
+
+    pub fn coverage(&self) -> Coverage {
+        Coverage::new(self.assignments())
+    }
+}
+
+impl Solution for Task {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        Self::parse(input)
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(self.supersets())
+    }
+
+    fn part2(&self) -> Result<usize> {
+        Ok(self.overlaps())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn supersets() {
+        let input = "\
+        2-4,6-8
+        2-3,4-5
+        5-7,7-9
+        2-8,3-7
+        6-6,4-6
+        2-6,4-8";
+
+        let counts = input
+            .lines()
+            .map(|l| l.parse::<Pair>().expect("expected a range"))
+            .filter(Pair::supersets)
+            .count();
+
+        assert_eq!(counts, 2);
+    }
+
+    #[test]
+    fn overlaps() {
+        let input = "\
+        2-4,6-8
+        2-3,4-5
+        5-7,7-9
+        2-8,3-7
+        6-6,4-6
+        2-6,4-8";
+
+        let counts = input
+            .lines()
+            .map(|l| l.parse::<Pair>().expect("expected a range"))
+            .filter(Pair::overlaps)
+            .count();
+
+        assert_eq!(counts, 4);
+    }
+}
@@ -0,0 +1 @@
+common::benchmarks!("day05", include_str!("../data/input.txt"), day05::Task);
@@ -0,0 +1,213 @@
+// The models of crane, which differ only in how the crates they lift off one stack end up on
+// another.  An instruction is checked against the stacks before anything is moved, so a crane
+// either carries out the whole instruction or leaves the stacks as they were and says which line of
+// the procedure it couldn't follow.
+use crate::{Crate, Instruction, Stacks};
+use color_eyre::{eyre::eyre, Report, Result};
+use std::fmt::{self, Display};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Problem {
+    NoSuchStack(usize),
+    NotEnoughCrates {
+        stack: usize,
+        count: usize,
+        height: usize,
+    },
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSuchStack(stack) => write!(f, "there's no stack {stack}"),
+            Self::NotEnoughCrates {
+                stack,
+                count,
+                height,
+            } => write!(f, "stack {stack} has {height} crates, not {count}"),
+        }
+    }
+}
+
+// An instruction a crane couldn't carry out, with the stacks numbered from 1 as in the procedure
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CraneError {
+    pub line: usize,
+    pub instruction: String,
+    pub problem: Problem,
+}
+
+impl Display for CraneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: {}: {}",
+            self.line, self.instruction, self.problem
+        )
+    }
+}
+
+impl std::error::Error for CraneError {}
+
+fn check(ins: &Instruction, stacks: &Stacks) -> std::result::Result<(), Problem> {
+    let stack = |i: usize| stacks.0.get(i).ok_or(Problem::NoSuchStack(i + 1));
+    let (src, _) = (stack(ins.src)?, stack(ins.dst)?);
+
+    if src.len() < ins.count {
+        return Err(Problem::NotEnoughCrates {
+            stack: ins.src + 1,
+            count: ins.count,
+            height: src.len(),
+        });
+    }
+    Ok(())
+}
+
+pub trait Crane {
+    fn name(&self) -> String;
+
+    // The crates lifted off a stack, bottom first, in the order they're put down on another
+    fn arrange(&self, crates: Vec<Crate>) -> Vec<Crate>;
+
+    fn apply(&self, ins: &Instruction, stacks: &mut Stacks) -> Result<()> {
+        check(ins, stacks).map_err(|problem| {
+            Report::new(CraneError {
+                line: ins.line,
+                instruction: ins.to_string(),
+                problem,
+            })
+        })?;
+
+        // However the crates are lifted, putting them back where they came from leaves the stack
+        // as it was
+        if ins.src != ins.dst {
+            let src = &mut stacks.0[ins.src];
+            let crates = src.split_off(src.len() - ins.count);
+            stacks.0[ins.dst].extend(self.arrange(crates));
+        }
+        Ok(())
+    }
+}
+
+// Moves one crate at a time, so the crates end up in the opposite order
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn name(&self) -> String {
+        "CrateMover 9000".to_owned()
+    }
+
+    fn arrange(&self, mut crates: Vec<Crate>) -> Vec<Crate> {
+        crates.reverse();
+        crates
+    }
+}
+
+// Moves all the crates at once, so they stay in the same order
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn name(&self) -> String {
+        "CrateMover 9001".to_owned()
+    }
+
+    fn arrange(&self, crates: Vec<Crate>) -> Vec<Crate> {
+        crates
+    }
+}
+
+// Moves up to `capacity` crates at once, taking as many loads as it needs, each of which stays in
+// order.  With a capacity of 1 it works like a 9000, and with one as big as any stack like a 9001.
+pub struct Bounded {
+    capacity: usize,
+}
+
+impl Bounded {
+    pub fn new(capacity: usize) -> Result<Self> {
+        if capacity == 0 {
+            return Err(eyre!("a crane has to be able to lift at least one crate"));
+        }
+        Ok(Self { capacity })
+    }
+}
+
+impl Crane for Bounded {
+    fn name(&self) -> String {
+        format!("crane lifting up to {}", self.capacity)
+    }
+
+    fn arrange(&self, crates: Vec<Crate>) -> Vec<Crate> {
+        crates.rchunks(self.capacity).flatten().copied().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Task;
+
+    #[test]
+    fn arrangements() {
+        let crates = "ABCDE".chars().map(Crate).collect::<Vec<_>>();
+        let arrange = |crane: &dyn Crane| {
+            crane
+                .arrange(crates.clone())
+                .into_iter()
+                .map(|c| c.0)
+                .collect::<String>()
+        };
+
+        assert_eq!(arrange(&CrateMover9000), "EDCBA");
+        assert_eq!(arrange(&CrateMover9001), "ABCDE");
+        assert_eq!(arrange(&Bounded::new(1).unwrap()), "EDCBA");
+        assert_eq!(arrange(&Bounded::new(2).unwrap()), "DEBCA");
+        assert_eq!(arrange(&Bounded::new(5).unwrap()), "ABCDE");
+        assert!(Bounded::new(0).is_err());
+    }
+
+    #[test]
+    fn bounded() {
+        let task = Task::parse(include_str!("../data/example.txt")).unwrap();
+
+        assert_eq!(task.top_crates(&Bounded::new(1).unwrap()).unwrap(), "CMZ");
+        assert_eq!(task.top_crates(&Bounded::new(2).unwrap()).unwrap(), "MCZ");
+        assert_eq!(task.top_crates(&Bounded::new(3).unwrap()).unwrap(), "MCD");
+    }
+
+    #[test]
+    fn bad_instructions() {
+        let error = |procedure: &str| {
+            let input = format!("[A] [B]\n 1   2 \n\n{procedure}\n");
+            let err = Task::parse(&input)
+                .unwrap()
+                .top_crates(&CrateMover9001)
+                .err()
+                .unwrap();
+            err.downcast::<CraneError>().unwrap()
+        };
+
+        let err = error("move 1 from 1 to 2\nmove 3 from 2 to 1");
+        assert_eq!(err.line, 5);
+        assert_eq!(err.instruction, "move 3 from 2 to 1");
+        assert_eq!(
+            err.problem,
+            Problem::NotEnoughCrates {
+                stack: 2,
+                count: 3,
+                height: 2
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "line 5: move 3 from 2 to 1: stack 2 has 2 crates, not 3"
+        );
+
+        assert_eq!(error("move 1 from 3 to 1").problem, Problem::NoSuchStack(3));
+        assert_eq!(error("move 1 from 1 to 9").problem, Problem::NoSuchStack(9));
+
+        // Moving crates onto the stack they came from changes nothing
+        let input = "[A] [B]\n[C] [D]\n 1   2 \n\nmove 2 from 1 to 1\n";
+        let task = Task::parse(input).unwrap();
+        assert_eq!(task.top_crates(&CrateMover9000).unwrap(), "AB");
+    }
+}
@@ -0,0 +1,53 @@
+// Inputs with between three and nine stacks and `size` steps in the procedure.  A step never
+// empties the stack it takes crates from, so that every stack has a crate on top at the end.
+use common::generate::{Rng, StdRng};
+use itertools::Itertools;
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let count = rng.gen_range(3..=9);
+    let mut heights = (0..count)
+        .map(|_| rng.gen_range(1..=8))
+        .collect::<Vec<usize>>();
+    heights[0] = heights[0].max(2);
+
+    let mut lines = vec![];
+    for level in (0..*heights.iter().max().unwrap()).rev() {
+        let row = heights.iter().map(|&height| {
+            if height > level {
+                format!("[{}]", rng.gen_range('A'..='Z'))
+            } else {
+                "   ".to_owned()
+            }
+        });
+        lines.push(row.collect_vec().join(" "));
+    }
+    lines.push((1..=count).map(|i| format!(" {i} ")).join(" "));
+    lines.push(String::new());
+
+    for _ in 0..size {
+        let src = loop {
+            let src = rng.gen_range(0..count);
+            if heights[src] > 1 {
+                break src;
+            }
+        };
+        let dst = (src + rng.gen_range(1..count)) % count;
+        let n = rng.gen_range(1..heights[src]);
+
+        heights[src] -= n;
+        heights[dst] += n;
+        lines.push(format!("move {n} from {} to {}", src + 1, dst + 1));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<crate::Task>(input, 30, 0..10).unwrap();
+    }
+}
@@ -0,0 +1,460 @@
+// Heavily copied from Amos's discussion, here:
+// https://fasterthanli.me/series/advent-of-code-2022/part-5#reader-suggestion-use-nom-s-number-parser
+
+use color_eyre::{eyre::eyre, Report, Result};
+use common::Solution;
+use itertools::Itertools;
+use std::{
+    fmt::{Debug, Display, Write},
+    str::FromStr,
+};
+
+pub mod crane;
+pub mod generate;
+pub mod trace;
+
+use crane::{Crane, CrateMover9000, CrateMover9001};
+use trace::Trace;
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Crate(char);
+
+impl Debug for Crate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_char(self.0)
+    }
+}
+
+impl Display for Crate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_char(self.0)
+    }
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    count: usize,
+    src: usize,
+    dst: usize,
+    // In the input, counting from 1
+    line: usize,
+}
+
+// As it was written in the procedure, with the stacks numbered from 1
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self {
+            count, src, dst, ..
+        } = self;
+        write!(f, "move {count} from {} to {}", src + 1, dst + 1)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Stacks(pub Vec<Vec<Crate>>);
+
+impl Stacks {
+    // With a space for a stack that's empty
+    pub fn top_crates(&self) -> String {
+        self.0
+            .iter()
+            .map(|stack| stack.last().map_or(' ', |krate| krate.0))
+            .collect()
+    }
+
+    #[allow(unused)]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+// A drawing of the stacks on its own, with the line numbering them but without a procedure after
+impl FromStr for Stacks {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut it = s.lines();
+        let stacks = parser::parse_stacks(s, &mut it)?.finalize();
+
+        match it.find(|line| !line.trim().is_empty()) {
+            Some(line) => Err(eyre!("unexpected line after the stacks: {line:?}")),
+            None => Ok(stacks),
+        }
+    }
+}
+
+// The drawing the stacks are parsed from, in the same layout as the puzzle: every row as wide as
+// the stacks, with empty spaces where a stack is shorter, and the line numbering the stacks last.
+// Each line ends with a newline.  A number is centred over its stack, or starts where the stack does
+// once it has two digits, so it's only with a thousand stacks or more that they stop lining up.
+impl Display for Stacks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let height = self.0.iter().map(Vec::len).max().unwrap_or_default();
+
+        for level in (0..height).rev() {
+            let row = self.0.iter().map(|stack| match stack.get(level) {
+                Some(krate) => format!("[{krate}]"),
+                None => "   ".to_owned(),
+            });
+            writeln!(f, "{}", row.format(" "))?;
+        }
+        writeln!(
+            f,
+            "{}",
+            (1..=self.0.len()).map(|i| format!("{i:^3}")).format(" ")
+        )
+    }
+}
+
+pub struct Task {
+    stacks: Stacks,
+    instructions: Vec<Instruction>,
+}
+
+impl Task {
+    pub fn parse(input: &str) -> Result<Self> {
+        use crate::parser::InstructionsIterExt;
+
+        let mut it = input.lines();
+        let stacks = parser::parse_stacks(input, &mut it)?.finalize();
+
+        // We're expecting a blank line
+        if it.next() != Some("") {
+            return Err(eyre!("expected a blank line after the stacks"));
+        }
+
+        let instructions = it.instructions(input).collect::<Result<_>>()?;
+        Ok(Self {
+            stacks,
+            instructions,
+        })
+    }
+
+    // The crates on top of the stacks once a crane has carried out the whole procedure
+    pub fn top_crates(&self, crane: &dyn Crane) -> Result<String> {
+        let mut stacks = self.stacks.clone();
+        for ins in &self.instructions {
+            crane.apply(ins, &mut stacks)?;
+        }
+        Ok(stacks.top_crates())
+    }
+
+    pub fn part1(&self) -> Result<String> {
+        self.top_crates(&CrateMover9000)
+    }
+
+    pub fn part2(&self) -> Result<String> {
+        self.top_crates(&CrateMover9001)
+    }
+
+    pub fn trace<'a>(&'a self, crane: &'a dyn Crane) -> Trace<'a> {
+        Trace::new(self.stacks.clone(), &self.instructions, crane)
+    }
+
+    // The first instruction that the two cranes carry out differently
+    pub fn first_divergence(&self) -> Result<Option<usize>> {
+        trace::first_divergence(
+            &self.stacks,
+            &self.instructions,
+            &CrateMover9000,
+            &CrateMover9001,
+        )
+    }
+}
+
+mod parser {
+    use std::collections::VecDeque;
+
+    use super::*;
+    use common::parse::{context, finish, IResult};
+    use nom::{
+        branch::alt,
+        bytes::complete::{tag, take},
+        character::complete::{char, space0, space1},
+        combinator::{all_consuming, cut, map, map_opt},
+        multi::separated_list1,
+        sequence::{delimited, preceded, tuple},
+    };
+
+    fn parse_crate(i: &str) -> IResult<&str, Crate> {
+        let first_char = |s: &str| Crate(s.chars().next().unwrap());
+        let f = delimited(tag("["), take(1_usize), tag("]"));
+        map(f, first_char)(i)
+    }
+
+    fn parse_hole(i: &str) -> IResult<&str, ()> {
+        map(tag("   "), drop)(i)
+    }
+
+    fn parse_crate_or_hole(i: &str) -> IResult<&str, Option<Crate>> {
+        context(
+            "a crate or a hole",
+            alt((map(parse_crate, Some), map(parse_hole, |_| None))),
+        )(i)
+    }
+
+    fn parse_crate_line(i: &str) -> IResult<&str, Vec<Option<Crate>>> {
+        separated_list1(char(' '), cut(parse_crate_or_hole))(i)
+    }
+
+    //  1   2   3
+    fn parse_stack_numbers(i: &str) -> IResult<&str, Vec<usize>> {
+        delimited(
+            space0,
+            separated_list1(space1, context("a stack number", parse_number)),
+            space0,
+        )(i)
+    }
+
+    // The rows of crates, top first, and the number of stacks in the line numbering them
+    #[derive(Debug)]
+    pub struct Ast(Vec<Vec<Option<Crate>>>, usize);
+
+    impl Ast {
+        pub fn finalize(self) -> Stacks {
+            let Self(crates, count) = self;
+
+            // Convert rows of crates to stacks, keeping the stacks that are empty
+            let width = crates.iter().map(Vec::len).max().unwrap_or_default();
+            let mut cols: Vec<VecDeque<Crate>> = vec![VecDeque::new(); width.max(count)];
+
+            for row in crates {
+                for (i, c) in row.into_iter().enumerate() {
+                    if let Some(c) = c {
+                        cols[i].push_front(c);
+                    }
+                }
+            }
+
+            Stacks(cols.into_iter().map(Vec::from).collect())
+        }
+    }
+
+    // The rows of crates, up to and including the line numbering the stacks
+    pub fn parse_stacks<'a, I>(input: &'a str, it: &mut I) -> Result<Ast>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        let mut crates = vec![];
+
+        for line in it {
+            if line.trim_start().starts_with(|c: char| c.is_ascii_digit()) {
+                let numbers = finish(input, all_consuming(parse_stack_numbers)(line))?;
+                return Ok(Ast(crates, numbers.len()));
+            }
+            crates.push(finish(input, all_consuming(parse_crate_line)(line))?);
+        }
+
+        Err(eyre!("expected a line numbering the stacks"))
+    }
+
+    fn parse_number(i: &str) -> IResult<&str, usize> {
+        map(nom::character::complete::u32, |n| n as _)(i)
+    }
+
+    fn parse_pile_number(i: &str) -> IResult<&str, usize> {
+        context(
+            "a stack number from 1",
+            map_opt(parse_number, |i| i.checked_sub(1)),
+        )(i)
+    }
+
+    fn parse_instruction(i: &str) -> IResult<&str, Instruction> {
+        context(
+            "a move",
+            map(
+                tuple((
+                    preceded(tag("move "), cut(parse_number)),
+                    cut(preceded(tag(" from "), parse_pile_number)),
+                    cut(preceded(tag(" to "), parse_pile_number)),
+                )),
+                |(count, src, dst)| Instruction {
+                    count,
+                    src,
+                    dst,
+                    line: 0,
+                },
+            ),
+        )(i)
+    }
+
+    // The line of the input that a slice of it starts on, counting from 1
+    fn line_number(input: &str, line: &str) -> usize {
+        let offset = (line.as_ptr() as usize).saturating_sub(input.as_ptr() as usize);
+        input[..offset.min(input.len())].matches('\n').count() + 1
+    }
+
+    // Parses the lines of an iterator over the input as instructions
+    pub struct Instructions<'a, Iter> {
+        input: &'a str,
+        iter: Iter,
+    }
+
+    impl<'a, Iter> Instructions<'a, Iter> {
+        pub fn new(input: &'a str, iter: Iter) -> Self {
+            Instructions { input, iter }
+        }
+    }
+
+    impl<'a, Iter> Iterator for Instructions<'a, Iter>
+    where
+        Iter: Iterator<Item = &'a str>,
+    {
+        type Item = Result<Instruction>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.iter.next().map(|line| {
+                let ins = finish(self.input, all_consuming(parse_instruction)(line))?;
+                Ok(Instruction {
+                    line: line_number(self.input, line),
+                    ..ins
+                })
+            })
+        }
+    }
+
+    pub trait InstructionsIterExt: Sized {
+        fn instructions(self, input: &str) -> Instructions<'_, Self>;
+    }
+
+    impl<Iter> InstructionsIterExt for Iter {
+        fn instructions(self, input: &str) -> Instructions<'_, Iter> {
+            Instructions::new(input, self)
+        }
+    }
+}
+
+impl Solution for Task {
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self> {
+        Self::parse(input)
+    }
+
+    fn part1(&self) -> Result<String> {
+        self.part1()
+    }
+
+    fn part2(&self) -> Result<String> {
+        self.part2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::InstructionsIterExt;
+    use common::parse::ParseError;
+
+    const INPUT: &str = "    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+    #[test]
+    fn crate_mover_9000() {
+        let mut it = INPUT.lines();
+        let mut stacks = parser::parse_stacks(INPUT, &mut it).unwrap().finalize();
+        assert_eq!(it.next().unwrap(), "");
+
+        for ins in it.instructions(INPUT).map(Result::unwrap) {
+            CrateMover9000.apply(&ins, &mut stacks).unwrap();
+        }
+
+        assert_eq!(stacks.top_crates(), "CMZ");
+    }
+
+    #[test]
+    fn crate_mover_9001() {
+        let mut it = INPUT.lines();
+        let mut stacks = parser::parse_stacks(INPUT, &mut it).unwrap().finalize();
+        assert_eq!(it.next().unwrap(), "");
+
+        for ins in it.instructions(INPUT).map(Result::unwrap) {
+            CrateMover9001.apply(&ins, &mut stacks).unwrap();
+        }
+
+        assert_eq!(stacks.top_crates(), "MCD");
+    }
+
+    #[test]
+    fn parse_input() {
+        let mut it = INPUT.lines();
+        let stacks = parser::parse_stacks(INPUT, &mut it).unwrap().finalize();
+        assert_eq!(stacks.len(), 3);
+
+        // We've consumed the line of crate labels, and now we're at the blank line
+        assert_eq!(it.next().unwrap(), "");
+
+        let ins = it.instructions(INPUT).collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(ins.len(), 4);
+    }
+
+    #[test]
+    fn render() {
+        let example = include_str!("../data/example.txt");
+        let drawing = &example[..example.find("\n\n").unwrap() + 1];
+        let stacks = drawing.parse::<Stacks>().unwrap();
+
+        assert_eq!(stacks.to_string(), drawing);
+        assert_eq!(stacks.to_string().parse::<Stacks>().unwrap(), stacks);
+        assert_eq!(
+            INPUT[..INPUT.find("\n\n").unwrap()]
+                .parse::<Stacks>()
+                .unwrap(),
+            stacks
+        );
+
+        // Stacks that have been emptied still get a column
+        let mut stacks = stacks;
+        stacks.0[0].clear();
+        stacks.0[2].clear();
+        let drawing = "    [D]    \n    [C]    \n    [M]    \n 1   2   3 \n";
+        assert_eq!(stacks.to_string(), drawing);
+        assert_eq!(drawing.parse::<Stacks>().unwrap(), stacks);
+
+        let stacks = Stacks(vec![vec![]; 2]);
+        assert_eq!(stacks.to_string(), " 1   2 \n");
+        assert_eq!(stacks.to_string().parse::<Stacks>().unwrap(), stacks);
+
+        // Numbers with two digits still fit in their columns
+        let mut stacks = Stacks(vec![vec![]; 11]);
+        stacks.0[9].push(Crate('X'));
+        stacks.0[10].push(Crate('Y'));
+        let drawing = format!(
+            "{}[X] [Y]\n 1   2   3   4   5   6   7   8   9  10  11 \n",
+            " ".repeat(36)
+        );
+        assert_eq!(stacks.to_string(), drawing);
+        assert_eq!(drawing.parse::<Stacks>().unwrap(), stacks);
+
+        assert!(format!("{stacks}\nmove 1 from 1 to 2")
+            .parse::<Stacks>()
+            .is_err());
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |input: &str| {
+            let err = Task::parse(input).err().unwrap();
+            let err = err.downcast_ref::<ParseError>().unwrap();
+            (err.line, err.column, err.expected.clone())
+        };
+
+        let input = INPUT.replace("[Z] [M] [P]", "[Z] x [P]");
+        assert_eq!(error(&input), (3, 5, "a crate or a hole".into()));
+
+        // A bad instruction used to end the procedure early
+        let input = INPUT.replace("move 2 from 2 to 1", "move 2 from two to 1");
+        assert_eq!(error(&input), (8, 13, "a number".into()));
+
+        let input = INPUT.replace("move 2 from 2 to 1", "move 2 from 2 to 0");
+        assert_eq!(error(&input), (8, 18, "a stack number from 1".into()));
+    }
+}
@@ -0,0 +1,211 @@
+// Running the procedure a step at a time, backwards as well as forwards.  Each step that has been
+// taken keeps the crates it lifted off the source stack, in the order they were stacked there,
+// which is all it takes to put them back whichever crane moved them.
+use crate::{crane::Crane, Crate, Instruction, Stacks};
+use color_eyre::Result;
+
+// The state of the stacks after some number of instructions
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Step {
+    pub position: usize,
+    pub stacks: Stacks,
+    pub top_crates: String,
+}
+
+pub struct Trace<'a> {
+    instructions: &'a [Instruction],
+    crane: &'a dyn Crane,
+    stacks: Stacks,
+    // For each instruction applied so far
+    taken: Vec<Vec<Crate>>,
+}
+
+impl<'a> Trace<'a> {
+    pub fn new(stacks: Stacks, instructions: &'a [Instruction], crane: &'a dyn Crane) -> Self {
+        Self {
+            instructions,
+            crane,
+            stacks,
+            taken: vec![],
+        }
+    }
+
+    // The number of instructions that have been applied
+    pub fn position(&self) -> usize {
+        self.taken.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.instructions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.instructions.is_empty()
+    }
+
+    pub fn stacks(&self) -> &Stacks {
+        &self.stacks
+    }
+
+    pub fn step(&self) -> Step {
+        Step {
+            position: self.position(),
+            stacks: self.stacks.clone(),
+            top_crates: self.stacks.top_crates(),
+        }
+    }
+
+    // Applies the next instruction, unless they've all been applied
+    pub fn forward(&mut self) -> Result<bool> {
+        let Some(ins) = self.instructions.get(self.position()) else {
+            return Ok(false);
+        };
+
+        // If the crates aren't there to take, the crane says so and nothing is kept
+        let taken = self.stacks.0.get(ins.src).map_or(vec![], |src| {
+            src[src.len().saturating_sub(ins.count)..].to_vec()
+        });
+        self.crane.apply(ins, &mut self.stacks)?;
+        self.taken.push(taken);
+        Ok(true)
+    }
+
+    // Undoes the last instruction applied, unless there isn't one
+    pub fn back(&mut self) -> bool {
+        let Some(taken) = self.taken.pop() else {
+            return false;
+        };
+
+        let ins = &self.instructions[self.position()];
+        let dst = &mut self.stacks.0[ins.dst];
+        dst.truncate(dst.len() - ins.count);
+        self.stacks.0[ins.src].extend(taken);
+        true
+    }
+
+    // Steps forwards or backwards until `position` instructions have been applied, or as near as
+    // there are instructions for
+    pub fn seek(&mut self, position: usize) -> Result<()> {
+        while self.position() > position && self.back() {}
+        while self.position() < position && self.forward()? {}
+        Ok(())
+    }
+
+    // The state after each of the remaining instructions
+    pub fn run(&mut self) -> Result<Vec<Step>> {
+        let mut steps = vec![];
+        while self.forward()? {
+            steps.push(self.step());
+        }
+        Ok(steps)
+    }
+}
+
+// The index of the first instruction after which two cranes leave the stacks differently, if they
+// ever do
+pub fn first_divergence(
+    stacks: &Stacks,
+    instructions: &[Instruction],
+    a: &dyn Crane,
+    b: &dyn Crane,
+) -> Result<Option<usize>> {
+    let mut a = Trace::new(stacks.clone(), instructions, a);
+    let mut b = Trace::new(stacks.clone(), instructions, b);
+
+    while a.forward()? && b.forward()? {
+        if a.stacks() != b.stacks() {
+            return Ok(Some(a.position() - 1));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        crane::{CrateMover9000, CrateMover9001},
+        Task,
+    };
+
+    fn example() -> Task {
+        Task::parse(include_str!("../data/example.txt")).unwrap()
+    }
+
+    #[test]
+    fn steps() {
+        let task = example();
+        let mut trace = task.trace(&CrateMover9000);
+        let tops = trace
+            .run()
+            .unwrap()
+            .into_iter()
+            .map(|step| step.top_crates)
+            .collect::<Vec<_>>();
+
+        assert_eq!(tops, ["DCP", " CZ", "M Z", "CMZ"]);
+        assert_eq!(trace.position(), 4);
+        assert!(!trace.forward().unwrap());
+
+        trace.seek(1).unwrap();
+        assert_eq!(trace.step().top_crates, "DCP");
+        assert_eq!(
+            trace.stacks().to_string(),
+            "[D]        \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n"
+        );
+
+        trace.seek(0).unwrap();
+        assert_eq!(trace.stacks(), &task.stacks);
+        assert!(!trace.back());
+
+        trace.seek(10).unwrap();
+        assert_eq!(trace.position(), 4);
+        assert_eq!(trace.step().top_crates, task.part1().unwrap());
+    }
+
+    #[test]
+    fn undo() {
+        let task = example();
+
+        for crane in [&CrateMover9000 as &dyn Crane, &CrateMover9001] {
+            let mut trace = task.trace(crane);
+            let forwards = trace.run().unwrap();
+
+            let mut backwards = vec![];
+            while trace.back() {
+                backwards.push(trace.step());
+            }
+
+            assert_eq!(trace.stacks(), &task.stacks);
+            backwards.pop();
+            backwards.reverse();
+            assert_eq!(backwards, forwards[..forwards.len() - 1]);
+        }
+    }
+
+    #[test]
+    fn bad_step() {
+        let task = Task::parse("[A]\n 1 \n\nmove 1 from 1 to 1\nmove 2 from 1 to 1\n").unwrap();
+        let mut trace = task.trace(&CrateMover9000);
+
+        assert!(trace.forward().unwrap());
+        assert!(trace.forward().is_err());
+        assert_eq!(trace.position(), 1);
+        assert!(trace.back());
+        assert_eq!(trace.stacks(), &task.stacks);
+    }
+
+    #[test]
+    fn divergence() {
+        let task = example();
+        assert_eq!(task.first_divergence().unwrap(), Some(1));
+
+        let same = first_divergence(
+            &task.stacks,
+            &task.instructions[..1],
+            &CrateMover9000,
+            &CrateMover9001,
+        );
+        assert_eq!(same.unwrap(), None);
+    }
+}
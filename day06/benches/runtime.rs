@@ -0,0 +1 @@
+common::benchmarks!("day06", include_str!("../data/input.txt"), day06::Task);
@@ -0,0 +1,41 @@
+// Datastreams of about `size` characters.  The stream starts with characters from too few letters
+// for either marker, then from enough letters for the start-of-packet marker but not for the
+// start-of-message marker, which comes after them.  The marker ends with the one letter that isn't
+// used before it, so that it is the first run of fourteen different characters.
+use common::generate::{SliceRandom, StdRng};
+
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+fn stream(rng: &mut StdRng, letters: &[u8], len: usize) -> Vec<u8> {
+    (0..len).map(|_| *letters.choose(rng).unwrap()).collect()
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let mut letters = LETTERS.to_vec();
+    letters.shuffle(rng);
+
+    let mut marker = letters[..13].to_vec();
+    marker.shuffle(rng);
+    marker.push(letters[13]);
+
+    let mut datastream = stream(rng, &letters[..3], size / 4 + 1);
+    datastream.extend(stream(rng, &letters[..13], size / 2 + 4));
+    datastream.extend(marker);
+    datastream.extend(stream(rng, LETTERS, size / 4));
+    String::from_utf8(datastream).unwrap() + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{message_start, packet_start};
+
+    #[test]
+    fn markers() {
+        for seed in 0..10 {
+            let input = input(&mut common::generate::rng(seed), 100);
+            assert!(packet_start(&input).unwrap() > 26);
+            assert_eq!(message_start(&input), Some(26 + 54 + 14));
+        }
+    }
+}
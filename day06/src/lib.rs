@@ -0,0 +1,66 @@
+use color_eyre::{eyre::eyre, Result};
+use common::Solution;
+use std::io::BufRead;
+
+pub mod generate;
+pub mod scanner;
+
+use scanner::Markers;
+
+// Where the first marker of `size` characters ends, reading no further than that
+pub fn first_marker(reader: impl BufRead, size: usize) -> Result<Option<usize>> {
+    let marker = Markers::new(reader, &[size])?.next().transpose()?;
+    Ok(marker.map(|marker| marker.end))
+}
+
+pub fn packet_start(s: &str) -> Option<usize> {
+    first_marker(s.as_bytes(), 4).ok().flatten()
+}
+
+pub fn message_start(s: &str) -> Option<usize> {
+    first_marker(s.as_bytes(), 14).ok().flatten()
+}
+
+pub struct Task(String);
+
+impl Solution for Task {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self(input.trim().to_owned()))
+    }
+
+    fn part1(&self) -> Result<usize> {
+        packet_start(&self.0).ok_or_else(|| eyre!("no start-of-packet marker"))
+    }
+
+    fn part2(&self) -> Result<usize> {
+        message_start(&self.0).ok_or_else(|| eyre!("no start-of-message marker"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packet() {
+        let f = packet_start;
+        assert_eq!(f("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), Some(7));
+        assert_eq!(f("bvwbjplbgvbhsrlpgdmjqwftvncz"), Some(5));
+        assert_eq!(f("nppdvjthqldpwncqszvftbrmjlhg"), Some(6));
+        assert_eq!(f("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), Some(10));
+        assert_eq!(f("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), Some(11));
+    }
+
+    #[test]
+    fn message() {
+        let f = message_start;
+        assert_eq!(f("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), Some(19));
+        assert_eq!(f("bvwbjplbgvbhsrlpgdmjqwftvncz"), Some(23));
+        assert_eq!(f("nppdvjthqldpwncqszvftbrmjlhg"), Some(23));
+        assert_eq!(f("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), Some(29));
+        assert_eq!(f("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), Some(26));
+    }
+}
@@ -0,0 +1,230 @@
+// Finds markers, runs of so many different characters in a row, in a datastream read a byte at a
+// time.  Whatever the sizes of marker looked for, the scanner only keeps where each byte was last
+// seen and where the current run of different bytes began, so every byte takes the same time and
+// the stream can be as long as it likes.
+use color_eyre::{eyre::eyre, Result};
+use std::{collections::VecDeque, io::BufRead};
+
+pub struct Scanner {
+    // Smallest first, without repeats
+    sizes: Vec<usize>,
+    // By byte, one past the position it was last seen at, or 0 if it hasn't been
+    last_seen: [usize; 256],
+    run_start: usize,
+    position: usize,
+}
+
+impl Scanner {
+    pub fn new(sizes: &[usize]) -> Result<Self> {
+        if sizes.contains(&0) {
+            return Err(eyre!("a marker is at least one character long"));
+        }
+
+        let mut sizes = sizes.to_vec();
+        sizes.sort_unstable();
+        sizes.dedup();
+
+        Ok(Self {
+            sizes,
+            last_seen: [0; 256],
+            run_start: 0,
+            position: 0,
+        })
+    }
+
+    // The number of bytes taken so far
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    // The length of the run of different bytes that ends with the last one taken
+    pub fn run(&self) -> usize {
+        self.position - self.run_start
+    }
+
+    // Takes the next byte, and gives the sizes of the markers that end with it, smallest first
+    pub fn push(&mut self, byte: u8) -> &[usize] {
+        let last_seen = &mut self.last_seen[byte as usize];
+        self.run_start = self.run_start.max(*last_seen);
+        self.position += 1;
+        *last_seen = self.position;
+
+        let run = self.run();
+        &self.sizes[..self.sizes.partition_point(|&size| size <= run)]
+    }
+}
+
+// A marker of `size` characters, ending after the first `end` characters of the datastream
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Marker {
+    pub size: usize,
+    pub end: usize,
+}
+
+const BOM: &[u8] = "\u{feff}".as_bytes();
+
+// Every marker in a datastream, in the order they end, and smallest first where several end
+// together.  The datastream ends at the end of its first line, and a byte order mark in front of it
+// is skipped.
+pub struct Markers<R> {
+    reader: R,
+    scanner: Scanner,
+    found: VecDeque<Marker>,
+    // How many bytes of a byte order mark the datastream starts with, until it's clear whether it
+    // starts with one at all, as a read can stop part-way through it
+    bom: Option<usize>,
+    done: bool,
+}
+
+fn take(scanner: &mut Scanner, found: &mut VecDeque<Marker>, byte: u8) {
+    let end = scanner.position() + 1;
+    found.extend(scanner.push(byte).iter().map(|&size| Marker { size, end }));
+}
+
+impl<R: BufRead> Markers<R> {
+    pub fn new(reader: R, sizes: &[usize]) -> Result<Self> {
+        Ok(Self {
+            reader,
+            scanner: Scanner::new(sizes)?,
+            found: VecDeque::new(),
+            bom: Some(0),
+            done: false,
+        })
+    }
+
+    // Reads until there's a marker to give, or the datastream ends
+    fn read(&mut self) -> Result<()> {
+        while self.found.is_empty() && !self.done {
+            let buf = self.reader.fill_buf()?;
+            if let Some(matched) = self.bom {
+                match buf.first() {
+                    Some(&byte) if matched < BOM.len() && byte == BOM[matched] => {
+                        self.reader.consume(1);
+                        self.bom = Some(matched + 1);
+                    }
+                    // What looked like the start of one was the start of the datastream after all
+                    _ => {
+                        self.bom = None;
+                        if matched < BOM.len() {
+                            for &byte in &BOM[..matched] {
+                                take(&mut self.scanner, &mut self.found, byte);
+                            }
+                        }
+                    }
+                }
+                continue;
+            }
+            if buf.is_empty() {
+                self.done = true;
+            }
+
+            let mut used = 0;
+            for &byte in buf {
+                used += 1;
+                if byte == b'\n' || byte == b'\r' {
+                    self.done = true;
+                    break;
+                }
+
+                take(&mut self.scanner, &mut self.found, byte);
+                if !self.found.is_empty() {
+                    break;
+                }
+            }
+            self.reader.consume(used);
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: BufRead> Iterator for Markers<R> {
+    type Item = Result<Marker>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Err(err) = self.read() {
+            self.done = true;
+            return Some(Err(err));
+        }
+        self.found.pop_front().map(Ok)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+
+    // Every marker by checking each window in turn
+    fn brute_force(s: &str, size: usize) -> Vec<usize> {
+        let bytes = s.as_bytes();
+        (size..=bytes.len())
+            .filter(|&end| {
+                let window = &bytes[end - size..end];
+                (0..size).all(|i| !window[i + 1..].contains(&window[i]))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn scanner() {
+        let mut scanner = Scanner::new(&[3, 1, 3]).unwrap();
+        let found = b"abca"
+            .iter()
+            .map(|&byte| scanner.push(byte).to_vec())
+            .collect::<Vec<_>>();
+
+        assert_eq!(found, [vec![1], vec![1], vec![1, 3], vec![1, 3]]);
+        assert_eq!(scanner.run(), 3);
+        assert!(Scanner::new(&[4, 0]).is_err());
+    }
+
+    #[test]
+    fn all_markers() {
+        let stream = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        let markers = Markers::new(stream.as_bytes(), &[14, 4])
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+
+        for size in [4, 14] {
+            let ends = markers
+                .iter()
+                .filter(|marker| marker.size == size)
+                .map(|marker| marker.end)
+                .collect::<Vec<_>>();
+            assert_eq!(ends, brute_force(stream, size));
+        }
+        assert_eq!(markers[0], Marker { size: 4, end: 7 });
+        assert!(markers
+            .windows(2)
+            .all(|w| (w[0].end, w[0].size) < (w[1].end, w[1].size)));
+    }
+
+    #[test]
+    fn streaming() {
+        let stream = "\u{feff}zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw\r\nabcd\r\n";
+        let reader = BufReader::with_capacity(4, stream.as_bytes());
+        let markers = Markers::new(reader, &[4])
+            .unwrap()
+            .map(|marker| marker.unwrap().end)
+            .collect::<Vec<_>>();
+
+        assert_eq!(markers, brute_force(&stream[3..35], 4));
+        assert_eq!(markers[0], 11);
+
+        // However the reads split it up
+        let markers = |stream: &[u8]| {
+            let reader = BufReader::with_capacity(1, stream);
+            Markers::new(reader, &[1, 4])
+                .unwrap()
+                .map(|marker| marker.unwrap().end)
+                .collect::<Vec<_>>()
+        };
+        let stream = stream.as_bytes();
+        assert_eq!(markers(stream), markers(&stream[3..]));
+        assert_eq!(markers(&stream[..2])[..], [1, 2]);
+        assert_eq!(markers(&[0xef, 0xbb, b'a', b'b'])[..], [1, 2, 3, 4, 4]);
+        assert!(markers(&[0xef, 0xbb, 0xbf]).is_empty());
+    }
+}
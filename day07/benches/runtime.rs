@@ -0,0 +1 @@
+common::benchmarks!("day07", include_str!("../data/input.txt"), day07::Task);
@@ -0,0 +1,342 @@
+// The filesystem the terminal output explores.  Directories keep their entries by name, so listing
+// a directory again or coming back to it finds the entries that are already there rather than
+// adding them twice, and a directory is still a directory when there's nothing in it.
+use color_eyre::{eyre::eyre, Result};
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Node {
+    File(usize),
+    Dir(BTreeMap<String, Node>),
+}
+
+impl Node {
+    fn dir() -> Self {
+        Self::Dir(BTreeMap::new())
+    }
+
+    pub fn is_dir(&self) -> bool {
+        matches!(self, Self::Dir(_))
+    }
+
+    // The size of a file, or of everything in a directory and below it
+    pub fn size(&self) -> usize {
+        match self {
+            Self::File(size) => *size,
+            Self::Dir(entries) => entries.values().map(Node::size).sum(),
+        }
+    }
+
+    fn entries(&self) -> impl Iterator<Item = (&String, &Node)> {
+        let entries = match self {
+            Self::File(_) => None,
+            Self::Dir(entries) => Some(entries),
+        };
+        entries.into_iter().flatten()
+    }
+}
+
+// A file or directory, found by walking the filesystem
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry<'a> {
+    pub path: String,
+    pub node: &'a Node,
+}
+
+impl Entry<'_> {
+    pub fn name(&self) -> &str {
+        match self.path.rsplit_once('/') {
+            Some((_, "")) | None => "/",
+            Some((_, name)) => name,
+        }
+    }
+}
+
+// The names along an absolute path like /a/e, with . and .. worked out, and .. at the root staying
+// at the root
+fn components(path: &str) -> Result<Vec<&str>> {
+    let rest = path
+        .strip_prefix('/')
+        .ok_or_else(|| eyre!("not an absolute path: {path}"))?;
+
+    let mut components = vec![];
+    for name in rest.split('/') {
+        match name {
+            "" | "." => {}
+            ".." => {
+                components.pop();
+            }
+            name => components.push(name),
+        }
+    }
+    Ok(components)
+}
+
+// The name of an entry in a directory, which can't be one that a path gives a meaning of its own
+fn check_name(name: &str) -> Result<()> {
+    match name {
+        "" | "." | ".." => Err(eyre!("{name:?} can't be the name of a file or directory")),
+        name if name.contains('/') => Err(eyre!("{name} has a / in it")),
+        _ => Ok(()),
+    }
+}
+
+fn path(components: &[String]) -> String {
+    format!("/{}", components.join("/"))
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Filesystem {
+    root: Node,
+    // Where the commands that build the filesystem have got to
+    cwd: Vec<String>,
+}
+
+impl Default for Filesystem {
+    fn default() -> Self {
+        Self {
+            root: Node::dir(),
+            cwd: vec![],
+        }
+    }
+}
+
+impl Filesystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn root(&self) -> &Node {
+        &self.root
+    }
+
+    pub fn cwd(&self) -> String {
+        path(&self.cwd)
+    }
+
+    pub fn resolve(&self, path: &str) -> Option<&Node> {
+        components(path)
+            .ok()?
+            .into_iter()
+            .try_fold(&self.root, |node, name| match node {
+                Node::Dir(entries) => entries.get(name),
+                Node::File(_) => None,
+            })
+    }
+
+    fn cwd_entries(&mut self) -> &mut BTreeMap<String, Node> {
+        let mut node = &mut self.root;
+        for name in &self.cwd {
+            node = match node {
+                Node::Dir(entries) => entries.get_mut(name).unwrap(),
+                Node::File(_) => unreachable!(),
+            };
+        }
+        match node {
+            Node::Dir(entries) => entries,
+            Node::File(_) => unreachable!(),
+        }
+    }
+
+    // Moving into a directory that hasn't been listed yet makes it
+    pub fn cd(&mut self, name: &str) -> Result<()> {
+        match name {
+            "/" => self.cwd.clear(),
+            "." => {}
+            ".." => {
+                self.cwd
+                    .pop()
+                    .ok_or_else(|| eyre!("/ has no parent directory"))?;
+            }
+            name => {
+                self.mkdir(name)?;
+                self.cwd.push(name.to_owned());
+            }
+        }
+        Ok(())
+    }
+
+    // A directory that's already there is left as it is
+    pub fn mkdir(&mut self, name: &str) -> Result<()> {
+        check_name(name)?;
+        let cwd = self.cwd();
+        match self
+            .cwd_entries()
+            .entry(name.to_owned())
+            .or_insert_with(Node::dir)
+        {
+            Node::Dir(_) => Ok(()),
+            Node::File(_) => Err(eyre!("{name} in {cwd} is a file, not a directory")),
+        }
+    }
+
+    // A file that's already there has to be the same size
+    pub fn touch(&mut self, name: &str, size: usize) -> Result<()> {
+        check_name(name)?;
+        let cwd = self.cwd();
+        match self
+            .cwd_entries()
+            .entry(name.to_owned())
+            .or_insert(Node::File(size))
+        {
+            Node::File(existing) if *existing == size => Ok(()),
+            Node::File(existing) => Err(eyre!(
+                "{name} in {cwd} was listed with a size of {existing} before, not {size}"
+            )),
+            Node::Dir(_) => Err(eyre!("{name} in {cwd} is a directory, not a file")),
+        }
+    }
+
+    // Every file and directory, each directory before what's in it, with the entries of a
+    // directory in order of name
+    pub fn walk(&self) -> Vec<Entry> {
+        fn walk<'a>(node: &'a Node, path: &mut Vec<String>, entries: &mut Vec<Entry<'a>>) {
+            entries.push(Entry {
+                path: self::path(path),
+                node,
+            });
+            for (name, child) in node.entries() {
+                path.push(name.clone());
+                walk(child, path, entries);
+                path.pop();
+            }
+        }
+
+        let mut entries = vec![];
+        walk(&self.root, &mut vec![], &mut entries);
+        entries
+    }
+
+    pub fn find(&self, predicate: impl Fn(&Entry) -> bool) -> Vec<Entry> {
+        self.walk().into_iter().filter(predicate).collect()
+    }
+
+    // The total size of a file or directory
+    pub fn du(&self, path: &str) -> Option<usize> {
+        self.resolve(path).map(Node::size)
+    }
+
+    // The size of every directory, by path
+    pub fn dir_sizes(&self) -> Vec<(String, usize)> {
+        self.find(|entry| entry.node.is_dir())
+            .into_iter()
+            .map(|entry| (entry.path, entry.node.size()))
+            .collect()
+    }
+
+    // Drawn the way the puzzle draws it, a line for each entry indented by its depth
+    pub fn tree(&self) -> String {
+        self.walk()
+            .iter()
+            .map(|entry| {
+                let depth = entry.path.matches('/').count() - (entry.path == "/") as usize;
+                let kind = match entry.node {
+                    Node::File(size) => format!("file, size={size}"),
+                    Node::Dir(_) => "dir".to_owned(),
+                };
+                format!(
+                    "{:indent$}- {} ({kind})\n",
+                    "",
+                    entry.name(),
+                    indent = 2 * depth
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Task;
+
+    fn example() -> Filesystem {
+        Task::parse(include_str!("../data/example.txt")).unwrap().0
+    }
+
+    #[test]
+    fn paths() {
+        let fs = example();
+
+        assert_eq!(fs.du("/"), Some(48381165));
+        assert_eq!(fs.du("/a/e"), Some(584));
+        assert_eq!(fs.du("/a/e/"), Some(584));
+        assert_eq!(fs.du("/a/../d/./k"), Some(7214296));
+        assert_eq!(fs.du("/../a"), Some(94853));
+        assert_eq!(fs.resolve("/d/k/x"), None);
+        assert_eq!(fs.resolve("/x"), None);
+        assert_eq!(fs.resolve("a"), None);
+        assert!(fs.resolve("/a").unwrap().is_dir());
+        assert_eq!(fs.cwd(), "/d");
+    }
+
+    #[test]
+    fn queries() {
+        let fs = example();
+
+        assert_eq!(
+            fs.tree(),
+            "\
+- / (dir)
+  - a (dir)
+    - e (dir)
+      - i (file, size=584)
+    - f (file, size=29116)
+    - g (file, size=2557)
+    - h.lst (file, size=62596)
+  - b.txt (file, size=14848514)
+  - c.dat (file, size=8504156)
+  - d (dir)
+    - d.ext (file, size=5626152)
+    - d.log (file, size=8033020)
+    - j (file, size=4060174)
+    - k (file, size=7214296)
+"
+        );
+
+        let big = fs.find(|entry| !entry.node.is_dir() && entry.node.size() > 8_000_000);
+        let big = big
+            .iter()
+            .map(|entry| entry.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(big, ["/b.txt", "/c.dat", "/d/d.log"]);
+
+        let sizes = fs.dir_sizes();
+        assert_eq!(sizes[1], ("/a".to_owned(), 94853));
+        assert_eq!(sizes.len(), 4);
+    }
+
+    #[test]
+    fn revisits() {
+        let input = "\
+            $ cd /\n$ ls\ndir a\n14 b\n$ cd a\n$ ls\n3 c\ndir empty\n$ cd /\n$ ls\ndir a\n14 b\n\
+            $ cd a\n$ ls\n3 c\ndir empty\n$ cd .\n$ cd ..\n$ cd new\n";
+        let fs = Task::parse(input).unwrap().0;
+
+        assert_eq!(fs.du("/"), Some(17));
+        assert_eq!(fs.walk().len(), 6);
+        assert!(fs.resolve("/a/empty").unwrap().is_dir());
+        assert!(fs.resolve("/new").unwrap().is_dir());
+    }
+
+    #[test]
+    fn conflicts() {
+        assert!(Task::parse("$ cd /\n$ ls\n14 b\n$ ls\n15 b\n").is_err());
+        assert!(Task::parse("$ cd /\n$ ls\n14 b\ndir b\n").is_err());
+        assert!(Task::parse("$ cd /\n$ ls\ndir b\n14 b\n").is_err());
+        assert!(Task::parse("$ cd /\n$ ls\n14 b\n$ cd b\n").is_err());
+        assert!(Task::parse("$ cd /\n$ cd ..\n").is_err());
+
+        // Names that would mean something else in a path
+        for line in [
+            "$ cd a/b", "$ cd /a", "dir a/b", "dir .", "dir ..", "14 ..", "14 /",
+        ] {
+            assert!(Task::parse(&format!("$ cd /\n$ ls\n{line}\n")).is_err());
+        }
+
+        let err = Task::parse("$ cd /\n$ ls\n14 b\n$ ls\n15 b\n")
+            .err()
+            .unwrap();
+        assert!(err.to_string().starts_with("line 5: "));
+    }
+}
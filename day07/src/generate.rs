@@ -0,0 +1,101 @@
+// Terminal output from exploring a filesystem with `size` directories.  Every directory has at
+// least one file in it, and the sizes are kept small enough for everything to fit on the disk.
+use common::generate::{Rng, StdRng};
+use std::collections::HashSet;
+
+struct Dir {
+    name: String,
+    files: Vec<(String, usize)>,
+    dirs: Vec<Dir>,
+}
+
+fn name(rng: &mut StdRng, used: &mut HashSet<String>, extension: bool) -> String {
+    loop {
+        let len = rng.gen_range(1..=8);
+        let mut name = (0..len)
+            .map(|_| rng.gen_range('a'..='z'))
+            .collect::<String>();
+        if extension && rng.gen_bool(0.5) {
+            name.push('.');
+            name.extend((0..3).map(|_| rng.gen_range('a'..='z')));
+        }
+
+        if used.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+// A directory with count directories below it
+fn dir(rng: &mut StdRng, name: String, count: usize) -> Dir {
+    let mut used = HashSet::new();
+    let files = (0..rng.gen_range(1..=4))
+        .map(|_| (self::name(rng, &mut used, true), 0))
+        .collect();
+
+    let children = if count == 0 {
+        0
+    } else {
+        rng.gen_range(1..=count.min(4))
+    };
+    let mut sizes = vec![0; children];
+    for _ in children..count {
+        sizes[rng.gen_range(0..children)] += 1;
+    }
+
+    let dirs = sizes
+        .into_iter()
+        .map(|size| {
+            let name = self::name(rng, &mut used, false);
+            dir(rng, name, size)
+        })
+        .collect();
+
+    Dir { name, files, dirs }
+}
+
+fn files(dir: &mut Dir) -> impl Iterator<Item = &mut usize> {
+    let nested: Box<dyn Iterator<Item = &mut usize>> =
+        Box::new(dir.dirs.iter_mut().flat_map(files));
+    dir.files.iter_mut().map(|(_, size)| size).chain(nested)
+}
+
+fn explore(dir: &Dir, lines: &mut Vec<String>) {
+    lines.push(format!("$ cd {}", dir.name));
+    lines.push("$ ls".to_owned());
+    lines.extend(dir.dirs.iter().map(|d| format!("dir {}", d.name)));
+    lines.extend(
+        dir.files
+            .iter()
+            .map(|(name, size)| format!("{size} {name}")),
+    );
+
+    for d in &dir.dirs {
+        explore(d, lines);
+        lines.push("$ cd ..".to_owned());
+    }
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let mut root = dir(rng, "/".to_owned(), size.saturating_sub(1));
+
+    let count = files(&mut root).count();
+    let largest = 60_000_000 / count;
+    for size in files(&mut root) {
+        *size = rng.gen_range(1..=largest);
+    }
+
+    let mut lines = vec![];
+    explore(&root, &mut lines);
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<crate::Task>(input, 30, 0..10).unwrap();
+    }
+}
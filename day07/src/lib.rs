@@ -0,0 +1,242 @@
+// Largely taken from https://fasterthanli.me/series/advent-of-code-2022/part-7#using-a-stack
+use color_eyre::{eyre::eyre, Result};
+use common::Solution;
+
+pub mod fs;
+pub mod generate;
+
+use fs::Filesystem;
+
+mod parser {
+    use super::*;
+    use common::parse::{context, finish, IResult};
+    use nom::{
+        branch::alt,
+        bytes::complete::{tag, take_while1},
+        character::complete::char,
+        combinator::{all_consuming, cut, map},
+        sequence::{preceded, tuple},
+    };
+
+    #[derive(Debug)]
+    pub enum Line {
+        Chdir(String),
+        Dir(String),
+        File(String, usize),
+        Ls,
+    }
+
+    fn parse_identifier(i: &str) -> IResult<&str, String> {
+        context(
+            "a name",
+            map(
+                take_while1(|c: char| "abcdefghijklmnopqrstuvwxyz./".contains(c)),
+                str::to_owned,
+            ),
+        )(i)
+    }
+
+    fn parse_chdir(i: &str) -> IResult<&str, Line> {
+        map(preceded(tag("$ cd "), cut(parse_identifier)), Line::Chdir)(i)
+    }
+
+    fn parse_dir(i: &str) -> IResult<&str, Line> {
+        map(preceded(tag("dir "), cut(parse_identifier)), Line::Dir)(i)
+    }
+
+    fn parse_number(i: &str) -> IResult<&str, usize> {
+        map(nom::character::complete::u32, |n| n as _)(i)
+    }
+
+    fn parse_file(i: &str) -> IResult<&str, Line> {
+        map(
+            tuple((parse_number, cut(preceded(char(' '), parse_identifier)))),
+            |(size, name)| Line::File(name, size),
+        )(i)
+    }
+
+    fn parse_ls(i: &str) -> IResult<&str, Line> {
+        map(tag("$ ls"), |_| Line::Ls)(i)
+    }
+
+    fn parse_line(i: &str) -> IResult<&str, Line> {
+        context(
+            "a command or a directory listing",
+            alt((parse_chdir, parse_dir, parse_file, parse_ls)),
+        )(i)
+    }
+
+    pub struct Ast(pub Vec<Line>);
+
+    impl Ast {
+        pub fn finalize(self) -> Result<Filesystem> {
+            let Self(lines) = self;
+            let mut fs = Filesystem::new();
+
+            for (i, line) in lines.into_iter().enumerate() {
+                let result = match line {
+                    Line::Chdir(name) => fs.cd(&name),
+                    Line::Dir(name) => fs.mkdir(&name),
+                    Line::File(name, size) => fs.touch(&name, size),
+                    Line::Ls => Ok(()),
+                };
+                result.map_err(|err| eyre!("line {}: {err}", i + 1))?;
+            }
+
+            Ok(fs)
+        }
+    }
+
+    // Every line has to parse: a line that doesn't is an error rather than the end of the output
+    pub fn parse(input: &str) -> Result<Ast> {
+        let lines = input
+            .lines()
+            .map(|line| finish(input, all_consuming(parse_line)(line.trim())))
+            .collect::<Result<_>>()?;
+
+        Ok(Ast(lines))
+    }
+}
+
+pub struct Task(Filesystem);
+
+impl Task {
+    fn new(fs: Filesystem) -> Self {
+        Self(fs)
+    }
+
+    pub fn filesystem(&self) -> &Filesystem {
+        &self.0
+    }
+
+    pub fn parse(input: &str) -> Result<Self> {
+        let root = parser::parse(input)?.finalize()?;
+        Ok(Self::new(root))
+    }
+
+    pub fn part1(&self) -> usize {
+        self.0
+            .dir_sizes()
+            .into_iter()
+            .map(|(_, size)| size)
+            .filter(|n| *n <= 100_000)
+            .sum()
+    }
+
+    pub fn part2(&self) -> usize {
+        let avail: usize = 70_000_000;
+        let used: usize = self.0.root().size();
+        let unused = avail.checked_sub(used).unwrap_or_default();
+        let need: usize = 30_000_000;
+        let free = need.checked_sub(unused).unwrap_or_default();
+
+        self.0
+            .dir_sizes()
+            .into_iter()
+            .map(|(_, size)| size)
+            .filter(|n| *n >= free)
+            .min()
+            .unwrap_or_default()
+    }
+}
+
+impl Solution for Task {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        Self::parse(input)
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(self.part1())
+    }
+
+    fn part2(&self) -> Result<usize> {
+        Ok(self.part2())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{parse, Line};
+    use common::parse::ParseError;
+
+    fn input<'s>() -> &'s str {
+        "$ cd /
+        $ ls
+        dir a
+        14848514 b.txt
+        8504156 c.dat
+        dir d
+        $ cd a
+        $ ls
+        dir e
+        29116 f
+        2557 g
+        62596 h.lst
+        $ cd e
+        $ ls
+        584 i
+        $ cd ..
+        $ cd ..
+        $ cd d
+        $ ls
+        4060174 j
+        8033020 d.log
+        5626152 d.ext
+        7214296 k"
+    }
+
+    #[test]
+    fn parse_ast() {
+        let ast = parse(input()).unwrap().0;
+        assert_eq!(ast.len(), 23);
+
+        assert!(matches!(
+            &ast[0],
+            Line::Chdir(name) if name == "/"));
+
+        assert!(matches!(&ast[1], Line::Ls));
+        assert!(matches!(&ast[2], Line::Dir(name) if name == "a"));
+        assert!(matches!(&ast[3], Line::File(name, size)
+            if name == "b.txt" && *size == 14848514 ));
+        assert!(matches!(&ast[22], Line::File(name, size)
+            if name == "k" && *size == 7214296 ));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |input| {
+            let err = parse(input).err().unwrap();
+            let err = err.downcast_ref::<ParseError>().unwrap();
+            (err.line, err.column, err.expected.clone())
+        };
+
+        // A bad line used to end the listing early, and the rest of it was quietly dropped
+        assert_eq!(
+            error("$ cd /\n$ ls\n$ cd Docs\n$ ls\n14 b.txt"),
+            (3, 6, "a name".into())
+        );
+        assert_eq!(
+            error("$ cd /\n$ ls\n14 b.txt\nrm b.txt"),
+            (4, 1, "a command or a directory listing".into())
+        );
+        assert_eq!(error("$ cd /\n$ ls\n14b.txt"), (3, 3, "' '".into()));
+    }
+
+    #[test]
+    fn part1() {
+        let root = parse(input()).unwrap().finalize().unwrap();
+        let task = Task::new(root);
+        assert_eq!(task.part1(), 95437);
+    }
+
+    #[test]
+    fn part2() {
+        let root = parse(input()).unwrap().finalize().unwrap();
+        let task = Task::new(root);
+        assert_eq!(task.part2(), 24933642);
+    }
+}
@@ -0,0 +1 @@
+common::benchmarks!("day08", include_str!("../data/input.txt"), day08::Task);
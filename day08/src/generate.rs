@@ -0,0 +1,22 @@
+// Square maps of tree heights, `size` trees across
+use common::generate::{Rng, StdRng};
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    (0..size)
+        .map(|_| {
+            let row = (0..size).map(|_| char::from(b'0' + rng.gen_range(0..=9)));
+            row.collect::<String>() + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<crate::Task>(input, 30, 0..10).unwrap();
+    }
+}
@@ -0,0 +1,51 @@
+// The patch of trees seen from above, a pixel per tree.  Taller trees are darker, trees that can be
+// seen from outside the patch are green and hidden ones grey, and the tree with the best scenic
+// score is picked out in gold.
+use crate::Task;
+use color_eyre::Result;
+use common::{
+    image::{blend, Image, Rgb},
+    Solution,
+};
+use grid::Pos;
+
+const VISIBLE: (Rgb, Rgb) = ([170, 220, 120], [20, 90, 30]);
+const HIDDEN: (Rgb, Rgb) = ([200, 200, 190], [80, 80, 75]);
+const BEST: Rgb = [250, 200, 40];
+
+pub fn draw(input: &str) -> Result<Image> {
+    let task = <Task as Solution>::parse(input)?;
+    let map = &task.map;
+    let best = map.positions().max_by_key(|&pos| task.scenic_score(pos));
+
+    Ok(Image::from_fn(map.width(), map.height(), |x, y| {
+        let pos = Pos::new(y, x);
+        let (short, tall) = match task.is_visible(pos) {
+            true => VISIBLE,
+            false => HIDDEN,
+        };
+
+        match Some(pos) == best {
+            true => BEST,
+            false => blend(short, tall, map[pos] as f64 / 9.0),
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let image = draw("30373\n25512\n65332\n33549\n35390\n").unwrap();
+
+        assert_eq!((image.width(), image.height()), (5, 5));
+        assert_eq!(image.get(2, 3), Some(BEST));
+        assert_eq!(
+            image.get(0, 0),
+            Some(blend(VISIBLE.0, VISIBLE.1, 3.0 / 9.0))
+        );
+        assert_eq!(image.get(3, 1), Some(blend(HIDDEN.0, HIDDEN.1, 1.0 / 9.0)));
+    }
+}
@@ -0,0 +1,132 @@
+use color_eyre::{eyre::eyre, Result};
+use common::Solution;
+use grid::{Grid, Offset, Pos};
+
+pub mod generate;
+pub mod image;
+
+pub struct Task {
+    map: Grid<i32>,
+}
+
+impl Task {
+    pub fn parse(lines: &[String]) -> Result<Self> {
+        let rows = lines.iter().map(|line| {
+            line.trim()
+                .chars()
+                .map(|c| c.to_digit(10).map(|d| d as i32))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| eyre!("not a row of tree heights: {line}"))
+        });
+
+        let map = Grid::from_rows(rows.collect::<Result<Vec<_>>>()?)?;
+        Ok(Self { map })
+    }
+
+    // A tree is visible from a direction if every tree between it and the edge is shorter
+    fn is_visible(&self, pos: Pos) -> bool {
+        let height = self.map[pos];
+        Offset::ORTHOGONAL.into_iter().any(|offset| {
+            self.map
+                .ray(pos, offset)
+                .all(|other| self.map[other] < height)
+        })
+    }
+
+    pub fn visible_trees(&self) -> usize {
+        self.map
+            .positions()
+            .filter(|&pos| self.is_visible(pos))
+            .count()
+    }
+
+    fn trees_ahead(&self, height: i32, pos: Pos, offset: Offset) -> i32 {
+        let mut count = 0;
+
+        for other in self.map.ray(pos, offset) {
+            count += 1;
+            if self.map[other] >= height {
+                break;
+            }
+        }
+
+        count
+    }
+
+    // Trees at the edge of the map see no trees in one direction, and so have a score of 0
+    fn scenic_score(&self, pos: Pos) -> i32 {
+        let height = self.map[pos];
+        Offset::ORTHOGONAL
+            .into_iter()
+            .map(|offset| self.trees_ahead(height, pos, offset))
+            .product()
+    }
+
+    // Is there an algorithm with less time complexity?
+    pub fn best_scenic_score(&self) -> i32 {
+        self.map
+            .positions()
+            .map(|pos| self.scenic_score(pos))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl Solution for Task {
+    type Part1 = usize;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self> {
+        Self::parse(&input.lines().map(str::to_owned).collect::<Vec<_>>())
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(self.visible_trees())
+    }
+
+    fn part2(&self) -> Result<i32> {
+        Ok(self.best_scenic_score())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input() -> &'static str {
+        "30373
+         25512
+         65332
+         33549
+         35390"
+    }
+
+    fn task() -> Task {
+        let lines: Vec<_> = input().lines().map(str::to_string).collect();
+        Task::parse(&lines).unwrap()
+    }
+
+    #[test]
+    fn visible_trees() {
+        let task = task();
+        assert_eq!(task.map.height(), 5);
+        assert_eq!(task.visible_trees(), 21);
+    }
+
+    #[test]
+    fn scenic_score() {
+        let task = task();
+
+        // Trees at the edge of the map have a score of 0
+        assert_eq!(task.scenic_score(Pos::new(0, 0)), 0);
+        assert_eq!(task.scenic_score(Pos::new(0, 4)), 0);
+        assert_eq!(task.scenic_score(Pos::new(4, 0)), 0);
+        assert_eq!(task.scenic_score(Pos::new(4, 4)), 0);
+
+        // Trees in the interior have a nonzero score
+        assert_eq!(task.scenic_score(Pos::new(1, 2)), 4);
+        assert_eq!(task.scenic_score(Pos::new(3, 2)), 8);
+
+        assert_eq!(task.best_scenic_score(), 8);
+    }
+}
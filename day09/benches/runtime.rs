@@ -0,0 +1 @@
+common::benchmarks!("day09", include_str!("../data/input.txt"), day09::Task);
@@ -0,0 +1,88 @@
+// The rope of ten knots from the second part, moved a step at a time.  The head is drawn as H and
+// the knots after it as 1 to 9, with s where the rope started and # wherever the tail has been.
+// The frame covers everywhere the rope has been, and so only ever grows.
+use crate::{Instructions, Knot, Position, Rope, Task};
+use color_eyre::Result;
+use common::{animate::Animation, Solution};
+use geometry::Bounds;
+use std::collections::HashSet;
+
+struct Moves {
+    steps: Instructions,
+    rope: Rope,
+    visited: HashSet<Knot>,
+    bounds: Bounds<Position>,
+}
+
+impl Moves {
+    fn new(task: Task) -> Result<Self> {
+        let rope = Rope::with_capacity(10)?;
+
+        Ok(Self {
+            steps: task.ins,
+            visited: HashSet::from([rope.tail()]),
+            rope,
+            bounds: Bounds::new(Position::default()),
+        })
+    }
+}
+
+impl Animation for Moves {
+    fn step(&mut self) -> bool {
+        let Some(step) = self.steps.next() else {
+            return false;
+        };
+
+        self.rope = self.rope.step(step);
+        self.visited.insert(self.rope.tail());
+        for knot in &self.rope.0 {
+            self.bounds.include(knot.0);
+        }
+
+        true
+    }
+
+    fn frame(&self) -> String {
+        let Bounds { min, max } = self.bounds;
+
+        (min.y..=max.y)
+            .flat_map(|y| {
+                (min.x..=max.x)
+                    .map(move |x| Position::new(x, y))
+                    .map(|p| match self.rope.0.iter().position(|knot| knot.0 == p) {
+                        Some(0) => 'H',
+                        Some(i) => char::from_digit(i as u32, 10).unwrap_or('*'),
+                        None if p == Position::default() => 's',
+                        None if self.visited.contains(&Knot(p)) => '#',
+                        None => '.',
+                    })
+                    .chain(['\n'])
+            })
+            .collect()
+    }
+}
+
+pub fn animation(input: &str) -> Result<Box<dyn Animation>> {
+    Ok(Box::new(Moves::new(<Task as Solution>::parse(input)?)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn larger_example() {
+        let input = "R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20\n";
+        let mut moves = Moves::new(<Task as Solution>::parse(input).unwrap()).unwrap();
+        assert_eq!(moves.frame(), "H\n");
+
+        let mut steps = 0;
+        while moves.step() {
+            steps += 1;
+        }
+
+        assert_eq!(steps, 96);
+        assert_eq!(moves.visited.len(), 36);
+        assert_eq!(moves.frame().lines().count(), 21);
+    }
+}
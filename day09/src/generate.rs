@@ -0,0 +1,21 @@
+// Series of `size` motions of the head of the rope
+use common::generate::{Rng, SliceRandom, StdRng};
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let dir = ['U', 'D', 'L', 'R'].choose(rng).unwrap();
+            format!("{dir} {}\n", rng.gen_range(1..=20))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<crate::Task>(input, 100, 0..10).unwrap();
+    }
+}
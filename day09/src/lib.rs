@@ -0,0 +1,286 @@
+use color_eyre::{eyre::eyre, Report, Result};
+use common::Solution;
+use geometry::{Dir, Vec2};
+use itertools::Itertools;
+use std::{collections::HashSet, fmt::Debug, str::FromStr};
+
+pub mod animate;
+pub mod generate;
+
+type Position = Vec2<i32>;
+
+#[derive(Clone, Debug)]
+struct Instruction {
+    steps: isize,
+    dir: Dir,
+}
+
+impl FromStr for Instruction {
+    type Err = Report;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (dir, steps) = s
+            .split(' ')
+            .collect_tuple()
+            .ok_or(eyre!("bad input: {s}"))?;
+        let steps = steps.parse::<isize>()?;
+
+        let direction = match dir {
+            "U" => Dir::Up,
+            "R" => Dir::Right,
+            "D" => Dir::Down,
+            "L" => Dir::Left,
+            _ => return Err(eyre!("bad direction: {dir}")),
+        };
+
+        Ok(Self {
+            dir: direction,
+            steps,
+        })
+    }
+}
+
+impl Instruction {
+    fn decrement(&mut self) {
+        self.steps -= 1;
+    }
+
+    fn is_empty(&self) -> bool {
+        self.steps <= 0
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Instructions(Vec<Instruction>);
+
+impl Instructions {
+    fn pop(&mut self) -> Option<Instruction> {
+        self.0.pop()
+    }
+
+    fn last_mut(&mut self) -> Option<&mut Instruction> {
+        self.0.last_mut()
+    }
+}
+
+impl Iterator for Instructions {
+    type Item = Position;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(ins) = self.last_mut() {
+            let step = Position::from(ins.dir);
+            ins.decrement();
+
+            if ins.is_empty() {
+                self.pop();
+            }
+
+            return Some(step);
+        }
+
+        None
+    }
+}
+
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+struct Knot(Position);
+
+impl Debug for Knot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("({},{})", self.0.x, self.0.y))
+    }
+}
+
+impl Knot {
+    #[allow(unused)]
+    fn new(x: i32, y: i32) -> Self {
+        Self(Vec2::new(x, y))
+    }
+
+    fn step(&mut self, step: Position) -> Self {
+        Self(self.0 + step)
+    }
+
+    // A knot that is no longer touching the previous one moves one step towards it, diagonally if
+    // they are not in the same row or column.
+    fn follow(&self, prev_knot: Self) -> Option<Self> {
+        if self.0.chebyshev(prev_knot.0) <= 1 {
+            return None;
+        }
+
+        Some(Knot(self.0 + (prev_knot.0 - self.0).signum()))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Rope(Vec<Knot>);
+
+impl Rope {
+    fn with_capacity(n: usize) -> Result<Self> {
+        if n < 2 {
+            return Err(eyre!("capacity cannot be less than 2"));
+        }
+
+        let v: Vec<Knot> = vec![Knot::default(); n];
+        Ok(Self(v))
+    }
+
+    fn step(&self, step: Position) -> Self {
+        let mut next_rope = self.clone();
+        let mut prev_knot = next_rope.0[0].step(step);
+        next_rope.0[0] = prev_knot;
+
+        for (i, u) in self.0.iter().enumerate() {
+            if i == 0 {
+                continue;
+            }
+
+            if let Some(v) = u.follow(prev_knot) {
+                next_rope.0[i] = v;
+                prev_knot = v;
+                continue;
+            }
+
+            break;
+        }
+
+        next_rope
+    }
+
+    fn tail(&self) -> Knot {
+        if let Some(knot) = self.0.last() {
+            return *knot;
+        }
+        unreachable!("rope has at least two knots");
+    }
+}
+
+pub struct Task {
+    ins: Instructions,
+}
+
+impl Task {
+    pub fn parse(lines: &[String]) -> Result<Self> {
+        let ins = lines
+            .iter()
+            .rev()
+            .map(|l| l.trim().parse::<Instruction>())
+            .collect::<Result<Vec<Instruction>>>()?;
+
+        Ok(Self {
+            ins: Instructions(ins),
+        })
+    }
+
+    pub fn part1(&self) -> usize {
+        self.positions_visited_by_tail(2).unwrap()
+    }
+
+    pub fn part2(&self) -> usize {
+        self.positions_visited_by_tail(10).unwrap()
+    }
+
+    fn run_scenario(&self, n: usize) -> Result<(Rope, HashSet<Knot>)> {
+        let mut prev = Rope::with_capacity(n)?;
+        let mut visited = HashSet::from([prev.tail()]);
+
+        for i in self.ins.clone() {
+            let next = prev.step(i);
+            visited.insert(next.tail());
+            prev = next;
+        }
+
+        Ok((prev, visited))
+    }
+
+    fn positions_visited_by_tail(&self, n: usize) -> Result<usize> {
+        let (_rope, visited) = self.run_scenario(n)?;
+        Ok(visited.len())
+    }
+}
+
+impl Solution for Task {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        Self::parse(&input.lines().map(str::to_owned).collect::<Vec<_>>())
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(self.part1())
+    }
+
+    fn part2(&self) -> Result<usize> {
+        Ok(self.part2())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(input: &str) -> Task {
+        let lines = input.lines().map(str::to_string).collect_vec();
+        Task::parse(&lines).unwrap()
+    }
+
+    #[test]
+    fn part1() {
+        let input = "\
+        R 4
+        U 4
+        L 3
+        D 1
+        R 4
+        D 1
+        L 5
+        R 2";
+
+        let task = task(input);
+        assert!(!task.ins.0.is_empty());
+        assert_eq!(task.part1(), 13);
+    }
+
+    #[test]
+    fn diagonal_move() {
+        let input = "\
+        R 4
+        U 4";
+
+        let task = task(input);
+        let (rope, _visited) = task.run_scenario(10).unwrap();
+        assert_eq!(
+            rope.0,
+            vec![
+                Knot::new(4, -4),
+                Knot::new(4, -3),
+                Knot::new(4, -2),
+                Knot::new(3, -2),
+                Knot::new(2, -2),
+                Knot::new(1, -1),
+                Knot::default(),
+                Knot::default(),
+                Knot::default(),
+                Knot::default(),
+            ]
+        );
+    }
+
+    #[test]
+    fn part2() {
+        let input = "\
+        R 5
+        U 8
+        L 8
+        D 3
+        R 17
+        D 10
+        L 25
+        U 20";
+
+        let task = task(input);
+        assert!(!task.ins.0.is_empty());
+        assert_eq!(task.part2(), 36);
+    }
+}
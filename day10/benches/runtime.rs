@@ -0,0 +1 @@
+common::benchmarks!("day10", include_str!("../data/input.txt"), day10::Task);
@@ -0,0 +1,31 @@
+// Programs of `size` instructions.  The program is repeated when it runs out before the CRT is
+// drawn, so the register is kept near the screen by nudging it back towards the middle.
+use common::generate::{Rng, StdRng};
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let mut x = 1;
+
+    (0..size.max(1))
+        .map(|_| {
+            if rng.gen_bool(0.3) {
+                return "noop\n".to_owned();
+            }
+
+            let target = rng.gen_range(0..40);
+            let v = (target - x).clamp(-20, 20);
+            let v = if v == 0 { 1 } else { v };
+            x += v;
+            format!("addx {v}\n")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<crate::Task>(input, 150, 0..10).unwrap();
+    }
+}
@@ -0,0 +1,35 @@
+// The CRT screen of the second part, a pixel for each of its pixels, glowing green on black.
+use crate::{Task, CRT_COLS, CRT_ROWS};
+use color_eyre::Result;
+use common::{
+    image::{Image, Rgb},
+    Solution,
+};
+
+const LIT: Rgb = [60, 255, 90];
+const DARK: Rgb = [10, 20, 10];
+
+pub fn draw(input: &str) -> Result<Image> {
+    let crt = <Task as Solution>::parse(input)?.part2()?;
+
+    Ok(Image::from_fn(CRT_COLS, CRT_ROWS, |x, y| {
+        match crt.0[y * CRT_COLS + x] {
+            true => LIT,
+            false => DARK,
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let image = draw(include_str!("../data/example.txt")).unwrap();
+
+        assert_eq!((image.width(), image.height()), (40, 6));
+        assert_eq!(image.get(0, 0), Some(LIT));
+        assert_eq!(image.get(2, 0), Some(DARK));
+    }
+}
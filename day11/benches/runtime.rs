@@ -0,0 +1 @@
+common::benchmarks!("day11", include_str!("../data/input.txt"), day11::Task);
@@ -0,0 +1,52 @@
+// Notes on `size` monkeys, between two and eight of them.  Each monkey tests for divisibility by a
+// different prime, as in the puzzle, so that worry levels can be kept small in the second part.
+use common::generate::{Rng, SliceRandom, StdRng};
+use itertools::Itertools;
+
+const PRIMES: [u64; 8] = [2, 3, 5, 7, 11, 13, 17, 19];
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let count = size.clamp(2, PRIMES.len());
+    let mut primes = PRIMES;
+    primes.shuffle(rng);
+
+    (0..count)
+        .map(|i| {
+            let items = (0..rng.gen_range(1..=6))
+                .map(|_| rng.gen_range(50..100))
+                .join(", ");
+
+            let operation = match rng.gen_range(0..5) {
+                0 => "old * old".to_owned(),
+                1 | 2 => format!("old * {}", rng.gen_range(2..=19)),
+                _ => format!("old + {}", rng.gen_range(1..=8)),
+            };
+
+            // Two different monkeys, neither of which is this one
+            let mut others = (0..count).filter(|&j| j != i).collect_vec();
+            others.shuffle(rng);
+            let (yes, no) = (others[0], others[others.len() - 1]);
+
+            format!(
+                "Monkey {i}:
+  Starting items: {items}
+  Operation: new = {operation}
+  Test: divisible by {}
+    If true: throw to monkey {yes}
+    If false: throw to monkey {no}
+",
+                primes[i]
+            )
+        })
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<crate::Task>(input, 8, 0..10).unwrap();
+    }
+}
@@ -1,14 +1,14 @@
 use std::{collections::VecDeque, rc::Rc};
 
-use color_eyre::{eyre::eyre, Result};
+use color_eyre::Result;
+use common::parse::{context, finish, IResult};
 use nom::{
     branch::alt,
     bytes::complete::tag,
     character::{complete::multispace1, streaming::multispace0},
-    combinator::{all_consuming, map, value},
+    combinator::{all_consuming, cut, map, value},
     multi::{fold_many1, separated_list1},
     sequence::{preceded, tuple},
-    Finish, IResult,
 };
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -110,13 +110,16 @@ fn parse_item_worry_levels(i: &str) -> IResult<&str, Vec<u64>> {
 
 // Starting items: 79, 98
 fn parse_items(i: &str) -> IResult<&str, Vec<u64>> {
-    map(
-        tuple((
-            tag("Starting items: "),
-            parse_item_worry_levels,
-            multispace1,
-        )),
-        |(_, ids, _)| ids,
+    context(
+        "the starting items",
+        map(
+            tuple((
+                tag("Starting items: "),
+                parse_item_worry_levels,
+                multispace1,
+            )),
+            |(_, ids, _)| ids,
+        ),
     )(i)
 }
 
@@ -154,9 +157,12 @@ fn parse_expression(i: &str) -> IResult<&str, Expression> {
 
 // Operation: new = old * 19
 fn parse_operation(i: &str) -> IResult<&str, Expression> {
-    map(
-        tuple((tag("Operation: "), parse_expression, multispace1)),
-        |(_, expression, _)| expression,
+    context(
+        "an operation",
+        map(
+            tuple((tag("Operation: "), parse_expression, multispace1)),
+            |(_, expression, _)| expression,
+        ),
     )(i)
 }
 
@@ -190,8 +196,15 @@ fn parse_branch(i: &str) -> IResult<&str, usize> {
 fn parse_test(i: &str) -> IResult<&str, Test> {
     type Components<'s> = (&'s str, u64, usize, usize);
 
+    let branch = |i| context("a monkey to throw to", parse_branch)(i);
+
     map(
-        tuple((tag("Test: "), parse_condition, parse_branch, parse_branch)),
+        tuple((
+            context("a test", tag("Test: ")),
+            context("a divisibility test", parse_condition),
+            branch,
+            branch,
+        )),
         |(_, divisible_by, branch_true, branch_false): Components| Test {
             divisible_by,
             branch_true,
@@ -201,7 +214,13 @@ fn parse_test(i: &str) -> IResult<&str, Test> {
 }
 
 fn parse_monkey(i: &str) -> IResult<&str, (Monkey, MonkeyState)> {
-    let components = tuple((parse_order, parse_items, parse_operation, parse_test));
+    // Everything after the first line has to be there once a monkey has started
+    let components = tuple((
+        parse_order,
+        cut(parse_items),
+        cut(parse_operation),
+        cut(parse_test),
+    ));
 
     map(components, |(order, items, op, test)| {
         (
@@ -239,10 +258,7 @@ fn parse_notes(i: &str) -> IResult<&str, Notes> {
 }
 
 pub(crate) fn parse(i: &str) -> Result<Notes> {
-    let (_s, program) = all_consuming(parse_notes)(i)
-        .finish()
-        .or(Err(eyre!("failed to parse input")))?;
-    Ok(program)
+    finish(i, all_consuming(parse_notes)(i))
 }
 
 #[cfg(test)]
@@ -333,4 +349,20 @@ mod tests {
         let notes = parse(input).unwrap();
         assert_eq!(notes.monkeys.len(), 4);
     }
+
+    #[test]
+    fn parse_errors() {
+        let error = |input: &str| {
+            let err = parse(input).unwrap_err();
+            let err = err.downcast_ref::<common::parse::ParseError>().unwrap();
+            (err.line, err.column, err.expected.clone())
+        };
+
+        let input = include_str!("../data/example.txt");
+        let bad = input.replacen("old * 19", "old ^ 19", 1);
+        assert_eq!(error(&bad), (3, 24, "an operation".into()));
+
+        let bad = input.replacen("monkey 3", "monkey three", 1);
+        assert_eq!(error(&bad), (6, 31, "a number".into()));
+    }
 }
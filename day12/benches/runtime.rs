@@ -0,0 +1 @@
+common::benchmarks!("day12", include_str!("../data/input.txt"), day12::Task);
@@ -0,0 +1,73 @@
+// Heightmaps `size` squares across, and at least fourteen squares across and down.  Neighbouring
+// squares never differ by more than one in elevation, which keeps every square reachable from every
+// other, and the map rises from its top left corner towards its bottom right corner so that there
+// is somewhere for the best signal to be.  No square is lower than it would need to be to climb to
+// the highest elevation by the bottom right corner, so the corner always gets there, however small
+// the map.
+use common::generate::{Rng, SliceRandom, StdRng};
+use grid::{Grid, Pos};
+
+fn heights(rng: &mut StdRng, width: usize, height: usize) -> Grid<u8> {
+    let mut grid = Grid::new(width, height, 0u8);
+    let slope = 25.0 / (width + height - 2) as f64;
+
+    for pos in grid.positions() {
+        let before = [
+            pos.row.checked_sub(1).map(|row| Pos::new(row, pos.col)),
+            pos.col.checked_sub(1).map(|col| Pos::new(pos.row, col)),
+        ];
+        let before = before.into_iter().flatten().map(|p| grid[p]);
+
+        let to_corner = (width - 1 - pos.col) + (height - 1 - pos.row);
+        let floor = 25usize.saturating_sub(to_corner) as u8;
+
+        let lo = before
+            .clone()
+            .max()
+            .map_or(0, |h| h.saturating_sub(1))
+            .max(floor);
+        let hi = before.min().map_or(0, |h| (h + 1).min(25));
+        let target = ((pos.row + pos.col) as f64 * slope).round() as u8;
+
+        grid[pos] = if rng.gen_bool(0.6) {
+            target.clamp(lo, hi)
+        } else {
+            rng.gen_range(lo..=hi)
+        };
+    }
+
+    grid
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let (width, height) = (size.max(14), (size / 4).max(14));
+
+    let grid = heights(rng, width, height);
+
+    let find = |rng: &mut StdRng, elevation| {
+        let found = grid.iter().filter(|&(_, &h)| h == elevation);
+        found
+            .map(|(pos, _)| pos)
+            .collect::<Vec<_>>()
+            .choose(rng)
+            .copied()
+    };
+    let start = find(rng, 0).unwrap();
+    let end = find(rng, 25).unwrap();
+
+    let mut map = grid.map(|&h| char::from(b'a' + h));
+    map[start] = 'S';
+    map[end] = 'E';
+    map.render(|&c| c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<crate::Task>(input, 40, 0..10).unwrap();
+        common::generate::check::<crate::Task>(input, 10, 0..10).unwrap();
+    }
+}
@@ -0,0 +1,89 @@
+// The elevation map, shaded from green valleys up to snowy peaks, with one of the shortest paths
+// from the start to the best signal drawn over it in red.
+use crate::{Map, Task};
+use color_eyre::{eyre::eyre, Result};
+use common::image::{blend, Image, Rgb};
+use grid::Pos;
+use std::collections::{HashMap, VecDeque};
+
+const LOW: Rgb = [40, 120, 50];
+const HIGH: Rgb = [240, 240, 250];
+const PATH: Rgb = [220, 30, 30];
+const START: Rgb = [30, 60, 220];
+const END: Rgb = [250, 200, 40];
+
+// A breadth-first search that keeps track of where each position was first reached from, so that
+// the path can be followed back from the end
+fn path(map: &Map) -> Option<Vec<Pos>> {
+    let mut came_from = HashMap::from([(map.start, map.start)]);
+    let mut queue = VecDeque::from([map.start]);
+
+    while let Some(u) = queue.pop_front() {
+        if u == map.end {
+            let mut path = vec![u];
+            while let Some(&pos) = path.last().filter(|&&pos| pos != map.start) {
+                path.push(came_from[&pos]);
+            }
+            path.reverse();
+            return Some(path);
+        }
+
+        for v in map.walkable_neighbors(u) {
+            came_from.entry(v).or_insert_with(|| {
+                queue.push_back(v);
+                u
+            });
+        }
+    }
+
+    None
+}
+
+pub fn draw(input: &str) -> Result<Image> {
+    let Task(map) = Task::parse(input)?;
+    let path = path(&map).ok_or_else(|| eyre!("no path to the end"))?;
+
+    let mut image = Image::from_fn(map.grid.width(), map.grid.height(), |x, y| {
+        let elevation = map.grid[Pos::new(y, x)].elevation();
+        blend(LOW, HIGH, elevation as f64 / 25.0)
+    });
+
+    for pos in &path {
+        image.set(pos.col, pos.row, PATH);
+    }
+    image.set(map.start.col, map.start.row, START);
+    image.set(map.end.col, map.end.row, END);
+
+    Ok(image)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi\n";
+
+    #[test]
+    fn shortest_path() {
+        let Task(map) = Task::parse(EXAMPLE).unwrap();
+        let path = path(&map).unwrap();
+
+        assert_eq!(path.len() - 1, 31);
+        assert_eq!(path.first(), Some(&map.start));
+        assert_eq!(path.last(), Some(&map.end));
+    }
+
+    #[test]
+    fn example() {
+        let image = draw(EXAMPLE).unwrap();
+        let path = (0..8)
+            .flat_map(|x| (0..5).map(move |y| (x, y)))
+            .filter(|&(x, y)| image.get(x, y) == Some(PATH))
+            .count();
+
+        assert_eq!((image.width(), image.height()), (8, 5));
+        assert_eq!(image.get(0, 0), Some(START));
+        assert_eq!(image.get(5, 2), Some(END));
+        assert_eq!(path, 30);
+    }
+}
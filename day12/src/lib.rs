@@ -0,0 +1,203 @@
+use color_eyre::{eyre::eyre, Result};
+use common::Solution;
+use grid::{Grid, Pos};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::{
+    collections::{BinaryHeap, HashSet},
+    fmt::Debug,
+};
+
+pub mod generate;
+pub mod image;
+
+#[derive(Debug, Eq, PartialEq)]
+enum Cell {
+    Start,
+    Height(u8),
+    End,
+}
+
+impl Cell {
+    fn elevation(&self) -> u8 {
+        match self {
+            Cell::Start => 0,
+            Cell::Height(h) => *h,
+            Cell::End => 25,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Map {
+    end: Pos,
+    grid: Grid<Cell>,
+    start: Pos,
+    lowest: Vec<Pos>,
+}
+
+impl Map {
+    fn parse(input: &str) -> Result<Self> {
+        let rows = input.trim().lines().map(|line| {
+            line.trim()
+                .chars()
+                .map(|c| match c {
+                    'S' => Ok(Cell::Start),
+                    'E' => Ok(Cell::End),
+                    'a'..='z' => Ok(Cell::Height(c as u8 - b'a')),
+                    _ => Err(eyre!("unknown elevation: {c}")),
+                })
+                .collect::<Result<Vec<_>>>()
+        });
+        let grid = Grid::from_rows(rows.collect::<Result<Vec<_>>>()?)?;
+
+        let find = |target: Cell| {
+            let mut found = grid.iter().filter(|&(_, cell)| *cell == target);
+            match (found.next(), found.next()) {
+                (Some((pos, _)), None) => Ok(pos),
+                (None, _) => Err(eyre!("missing {target:?}")),
+                _ => Err(eyre!("{target:?} already seen")),
+            }
+        };
+        let start = find(Cell::Start)?;
+        let end = find(Cell::End)?;
+
+        let lowest = grid
+            .iter()
+            .filter(|(_, cell)| cell.elevation() == 0)
+            .map(|(pos, _)| pos)
+            .collect();
+
+        Ok(Self {
+            end,
+            lowest,
+            grid,
+            start,
+        })
+    }
+
+    fn walkable_neighbors(&self, u: Pos) -> impl Iterator<Item = Pos> + '_ {
+        let curr_elev = self.grid[u].elevation();
+        self.grid
+            .neighbors4(u)
+            .filter(move |&v| self.grid[v].elevation() <= curr_elev + 1)
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct Step {
+    steps: i32,
+    pos: Pos,
+}
+
+impl Ord for Step {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.steps.cmp(&other.steps)
+    }
+}
+
+impl PartialOrd for Step {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug)]
+pub struct Task(Map);
+
+impl Task {
+    pub fn parse(input: &str) -> Result<Self> {
+        let map = Map::parse(input)?;
+        Ok(Task(map))
+    }
+
+    // Thanks to https://github.com/NickyMeuleman/scrapyard/blob/main/advent_of_code/2022/src/day_12.rs
+    fn mininium_steps(&self, u: Pos) -> Option<i32> {
+        let map = &self.0;
+
+        let mut visited = HashSet::from([map.start]);
+        let mut pq = BinaryHeap::from([Step { steps: 0, pos: u }]);
+
+        while let Some(Step { steps, pos: u }) = pq.pop() {
+            if u == map.end {
+                return Some(-steps);
+            }
+
+            for v in map.walkable_neighbors(u) {
+                if visited.insert(v) {
+                    pq.push(Step {
+                        steps: steps - 1,
+                        pos: v,
+                    });
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn part1(&self) -> Option<i32> {
+        self.mininium_steps(self.0.start)
+    }
+
+    pub fn part2(&self) -> Option<i32> {
+        // TODO: Perhaps there's a more time-efficient approach?
+        #[cfg(feature = "parallel")]
+        let lowest = self.0.lowest.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let lowest = self.0.lowest.iter();
+
+        lowest.filter_map(|u| self.mininium_steps(*u)).min()
+    }
+}
+
+impl Solution for Task {
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self> {
+        Self::parse(input)
+    }
+
+    fn part1(&self) -> Result<i32> {
+        self.part1().ok_or_else(|| eyre!("no path to the end"))
+    }
+
+    fn part2(&self) -> Result<i32> {
+        self.part2().ok_or_else(|| eyre!("no path to the end"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task() -> Task {
+        let input = "\
+        Sabqponm
+        abcryxxl
+        accszExk
+        acctuvwj
+        abdefghi";
+        Task::parse(input).unwrap()
+    }
+
+    #[test]
+    fn parsing() {
+        let Task(map) = task();
+        assert_eq!(map.start, Pos::new(0, 0));
+        assert_eq!(map.end, Pos::new(2, 5));
+    }
+
+    #[test]
+    fn part1() {
+        let task = task();
+        assert_eq!(task.part1().unwrap(), 31);
+    }
+
+    #[test]
+    fn part2() {
+        let task = task();
+        assert_eq!(task.part2().unwrap(), 29);
+    }
+}
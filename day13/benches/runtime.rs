@@ -0,0 +1 @@
+common::benchmarks!("day13", include_str!("../data/input.txt"), day13::Task);
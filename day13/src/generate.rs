@@ -0,0 +1,93 @@
+// `size` pairs of packets.  The right packet of a pair is often a copy of the left one with a small
+// change somewhere inside it, so that the two have to be compared some way in before they differ.
+// Packets that would be taken for a divider packet are never made.
+use common::generate::{Rng, StdRng};
+use itertools::Itertools;
+
+#[derive(Clone)]
+enum Item {
+    Number(u8),
+    List(Vec<Item>),
+}
+
+impl Item {
+    fn random(rng: &mut StdRng, depth: usize) -> Self {
+        if depth == 0 || rng.gen_bool(0.6) {
+            return Self::Number(rng.gen_range(0..=10));
+        }
+        Self::list(rng, depth)
+    }
+
+    fn list(rng: &mut StdRng, depth: usize) -> Self {
+        let len = rng.gen_range(0..=5);
+        Self::List((0..len).map(|_| Self::random(rng, depth - 1)).collect())
+    }
+
+    // Changes, adds or removes one item somewhere inside
+    fn mutate(&mut self, rng: &mut StdRng) {
+        match self {
+            Self::Number(n) => *n = rng.gen_range(0..=10),
+            Self::List(items) => match rng.gen_range(0..4) {
+                0 if !items.is_empty() => {
+                    items.pop();
+                }
+                1 => items.push(Self::random(rng, 1)),
+                _ if !items.is_empty() => {
+                    let i = rng.gen_range(0..items.len());
+                    items[i].mutate(rng);
+                }
+                _ => items.push(Self::Number(rng.gen_range(0..=10))),
+            },
+        }
+    }
+
+    fn render(&self) -> String {
+        match self {
+            Self::Number(n) => n.to_string(),
+            Self::List(items) => format!("[{}]", items.iter().map(Self::render).join(",")),
+        }
+    }
+}
+
+fn packet(rng: &mut StdRng) -> Item {
+    loop {
+        let item = Item::list(rng, 4);
+        let rendered = item.render();
+        if rendered != "[[2]]" && rendered != "[[6]]" {
+            break item;
+        }
+    }
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let left = packet(rng);
+            let right = if rng.gen_bool(0.7) {
+                let mut right = left.clone();
+                right.mutate(rng);
+                right
+            } else {
+                packet(rng)
+            };
+
+            // The change may have turned the copy into a divider packet
+            let right = match right.render().as_str() {
+                "[[2]]" | "[[6]]" => packet(rng),
+                _ => right,
+            };
+
+            format!("{}\n{}\n", left.render(), right.render())
+        })
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<crate::Task>(input, 50, 0..10).unwrap();
+    }
+}
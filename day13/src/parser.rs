@@ -1,12 +1,12 @@
-use color_eyre::{eyre::eyre, Report, Result};
+use color_eyre::{Report, Result};
+use common::parse::{context, finish, IResult};
 use lazy_static::lazy_static;
 use nom::{
     branch::alt,
     character::complete::{char, multispace0, multispace1},
-    combinator::{all_consuming, map},
-    multi::{many1, separated_list0},
-    sequence::{delimited, tuple},
-    Finish, IResult,
+    combinator::{all_consuming, cut, map, opt},
+    multi::{many0, many1},
+    sequence::{delimited, preceded, tuple},
 };
 use std::{fmt::Debug, str::FromStr};
 
@@ -72,11 +72,7 @@ impl FromStr for Packet {
     type Err = Report;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        let packet = all_consuming(parse_packet)(s)
-            .finish()
-            .or(Err(eyre!("failed to parse input")))?
-            .1;
-        Ok(packet)
+        finish(s, all_consuming(parse_packet)(s))
     }
 }
 
@@ -123,14 +119,18 @@ fn parse_number(i: &str) -> IResult<&str, Item> {
     map(nom::character::complete::u16, Item::Number)(i)
 }
 
+fn parse_item(i: &str) -> IResult<&str, Item> {
+    context("a number or a list", alt((parse_number, parse_list)))(i)
+}
+
+// Once a list has been opened, anything but items separated by commas and a closing bracket is an
+// error, rather than the end of the signal
 fn parse_list(i: &str) -> IResult<&str, Item> {
+    let items = tuple((opt(parse_item), many0(preceded(char(','), cut(parse_item)))));
+
     map(
-        delimited(
-            char('['),
-            separated_list0(char(','), alt((parse_number, parse_list))),
-            char(']'),
-        ),
-        Item::List,
+        delimited(char('['), items, cut(char(']'))),
+        |(first, rest)| Item::List(first.into_iter().chain(rest).collect()),
     )(i)
 }
 
@@ -140,8 +140,15 @@ fn parse_packet(i: &str) -> IResult<&str, Packet> {
 
 fn parse_pair(i: &str) -> IResult<&str, Pair> {
     map(
-        tuple((parse_packet, multispace1, parse_packet, multispace0)),
-        |(left, _, right, _)| Pair { left, right },
+        tuple((
+            parse_packet,
+            cut(context(
+                "a second packet",
+                preceded(multispace1, parse_packet),
+            )),
+            multispace0,
+        )),
+        |(left, right, _)| Pair { left, right },
     )(i)
 }
 
@@ -150,11 +157,7 @@ fn parse_signal(i: &str) -> IResult<&str, Signal> {
 }
 
 pub fn parse(input: &str) -> Result<Signal> {
-    let input = input.trim();
-    let (_rest, signal) = all_consuming(parse_signal)(input)
-        .finish()
-        .or(Err(eyre!("failed to parse input")))?;
-    Ok(signal)
+    finish(input, all_consuming(parse_signal)(input.trim()))
 }
 
 #[cfg(test)]
@@ -259,4 +262,17 @@ mod tests {
         assert!(parse_packet("[[6]]").unwrap().1.is_divider());
         assert!(!parse_packet("[2]").unwrap().1.is_divider());
     }
+
+    #[test]
+    fn parse_errors() {
+        let error = |input: &str| {
+            let err = parse(input).unwrap_err();
+            let err = err.downcast_ref::<common::parse::ParseError>().unwrap();
+            (err.line, err.column, err.expected.clone())
+        };
+
+        assert_eq!(error("[1,[2]]\n[1,x]"), (2, 4, "a number or a list".into()));
+        assert_eq!(error("[1,[2]]\n[1,2"), (2, 5, "']'".into()));
+        assert_eq!(error("[1]\n[2]\n\n[3]"), (4, 4, "a second packet".into()));
+    }
 }
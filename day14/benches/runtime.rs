@@ -0,0 +1 @@
+common::benchmarks!("day14", include_str!("../data/input.txt"), day14::Task);
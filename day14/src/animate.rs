@@ -0,0 +1,73 @@
+// The sand of the first part falling into the cave, each grain moving a step at a time.  Falling
+// sand is drawn as ~ and sand at rest as o.  It comes to an end once sand starts falling into the
+// abyss below the rock, or the source is blocked.
+use crate::{Cell, Simulation, Task, SPAWN_POINT};
+use color_eyre::Result;
+use common::animate::Animation;
+
+struct Sand {
+    simulation: Simulation,
+    done: bool,
+}
+
+impl Animation for Sand {
+    fn step(&mut self) -> bool {
+        if self.done {
+            return false;
+        }
+
+        let (grains, settled) = (self.simulation.grains.len(), self.simulation.settled);
+        self.simulation.step();
+
+        // Every grain that went is either at rest now or has fallen out of the cave
+        let kept = self.simulation.grains.len() - !self.simulation.filled as usize;
+        let fallen = grains - kept - (self.simulation.settled - settled);
+        self.done = fallen > 0 || self.simulation.filled;
+
+        true
+    }
+
+    fn frame(&self) -> String {
+        let cave = &self.simulation.cave;
+        let mut grid = cave.grid.map(|cell| match cell {
+            Cell::Air => '.',
+            Cell::Rock => '#',
+            Cell::Sand => 'o',
+        });
+
+        if let Some(pos) = cave.pos(SPAWN_POINT) {
+            if grid[pos] == '.' {
+                grid[pos] = '+';
+            }
+        }
+        for &grain in &self.simulation.grains {
+            if let Some(pos) = cave.pos(grain).filter(|&pos| grid.contains(pos)) {
+                grid[pos] = '~';
+            }
+        }
+
+        grid.render(|&c| c)
+    }
+}
+
+pub fn animation(input: &str) -> Result<Box<dyn Animation>> {
+    let cave = Task::parse(input)?.polylines.to_cave()?;
+
+    Ok(Box::new(Sand {
+        simulation: cave.simulation(),
+        done: false,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let mut sand = animation(include_str!("../data/example.txt")).unwrap();
+        while sand.step() {}
+
+        assert_eq!(sand.frame().matches('o').count(), 24);
+    }
+}
@@ -0,0 +1,39 @@
+// Scans of `size` rock paths, each made of up to four horizontal and vertical lines.  The rock lies
+// within a box below the source of the sand that grows with the size, and never covers the source.
+use common::generate::{Rng, StdRng};
+use itertools::Itertools;
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let spread = size.max(5) as i32;
+    let depth = 2..=spread + 2;
+
+    (0..size)
+        .map(|_| {
+            let mut x = rng.gen_range(500 - spread..=500 + spread);
+            let mut y = rng.gen_range(depth.clone());
+            let mut points = vec![(x, y)];
+
+            let horizontal = rng.gen_bool(0.5);
+            for i in 0..rng.gen_range(1..=4) {
+                if (i % 2 == 0) == horizontal {
+                    x = (x + rng.gen_range(-6..=6)).clamp(500 - spread, 500 + spread);
+                } else {
+                    y = (y + rng.gen_range(-4..=4)).clamp(*depth.start(), *depth.end());
+                }
+                points.push((x, y));
+            }
+
+            points.iter().map(|(x, y)| format!("{x},{y}")).join(" -> ") + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<crate::Task>(input, 10, 0..10).unwrap();
+    }
+}
@@ -0,0 +1,26 @@
+// The points along each line of a polyline in turn, from a generator.  This needs nightly, and
+// even the yield syntax can't be left in a file that stable compiles.
+use crate::{Point, Polyline};
+
+impl Polyline {
+    pub(crate) fn path_points(&self) -> impl Iterator<Item = Point> + '_ {
+        std::iter::from_generator(|| {
+            let mut points = self.points.iter().copied();
+            let Some(mut a) = points.next() else { return };
+            yield a;
+
+            loop {
+                let Some(b) = points.next() else { return };
+                let delta = (b - a).signum();
+
+                loop {
+                    a += delta;
+                    yield a;
+                    if a == b {
+                        break;
+                    }
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,46 @@
+// The cave once the sand of the first part has come to rest, a pixel per cell, with the source of
+// the sand marked in red.
+use crate::{Cell, Task, SPAWN_POINT};
+use color_eyre::Result;
+use common::image::{Image, Rgb};
+
+const AIR: Rgb = [25, 25, 40];
+const ROCK: Rgb = [120, 110, 100];
+const SAND: Rgb = [230, 190, 110];
+const SOURCE: Rgb = [220, 40, 30];
+
+pub fn draw(input: &str) -> Result<Image> {
+    let task = Task::parse(input)?;
+    let cave = task.settle(&task.polylines.to_cave()?, 100).cave;
+
+    let mut image = Image::from_fn(cave.grid.width(), cave.grid.height(), |x, y| {
+        match cave.grid[grid::Pos::new(y, x)] {
+            Cell::Air => AIR,
+            Cell::Rock => ROCK,
+            Cell::Sand => SAND,
+        }
+    });
+    if let Some(pos) = cave.pos(SPAWN_POINT) {
+        image.set(pos.col, pos.row, SOURCE);
+    }
+
+    Ok(image)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let image = draw(include_str!("../data/example.txt")).unwrap();
+        let sand = (0..image.width())
+            .flat_map(|x| (0..image.height()).map(move |y| (x, y)))
+            .filter(|&(x, y)| image.get(x, y) == Some(SAND))
+            .count();
+
+        assert_eq!((image.width(), image.height()), (10, 10));
+        assert_eq!(image.get(6, 0), Some(SOURCE));
+        assert_eq!(sand, 24);
+    }
+}
@@ -0,0 +1,366 @@
+// Re-worked along the lines of Amos in https://fasterthanli.me/series/advent-of-code-2022/part-14
+#![cfg_attr(feature = "nightly", feature(iter_from_generator))]
+#![cfg_attr(feature = "nightly", feature(generators))]
+#![cfg_attr(feature = "nightly", feature(drain_filter))]
+
+use color_eyre::{eyre::eyre, Report, Result};
+use common::Solution;
+use geometry::{Bounds, Vec2};
+use grid::{Grid, Pos};
+use itertools::Itertools;
+use std::{fmt::Debug, str::FromStr};
+
+pub mod animate;
+pub mod generate;
+#[cfg(feature = "nightly")]
+mod generator;
+pub mod image;
+
+type Point = Vec2<i32>;
+
+const SPAWN_POINT: Point = Point::new(500, 0);
+
+fn parse_point(s: &str) -> Result<Point> {
+    let (x, y) = s
+        .split(',')
+        .collect_tuple()
+        .ok_or(eyre!("bad input: {s}"))?;
+
+    Ok(Point::new(x.parse()?, y.parse()?))
+}
+
+#[derive(Clone, Debug)]
+struct Polyline {
+    points: Vec<Point>,
+}
+
+impl FromStr for Polyline {
+    type Err = Report;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let points = s
+            .trim()
+            .split(" -> ")
+            .map(parse_point)
+            .collect::<Result<Vec<Point>>>()?;
+
+        Ok(Self { points })
+    }
+}
+
+impl Polyline {
+    // The points along each line in turn.  There's a generator for this on nightly.
+    #[cfg(not(feature = "nightly"))]
+    fn path_points(&self) -> impl Iterator<Item = Point> + '_ {
+        let lines = self.points.windows(2).flat_map(|line| {
+            let (a, b) = (line[0], line[1]);
+            let delta = (b - a).signum();
+            std::iter::successors(Some(a), move |&p| (p != b).then_some(p + delta)).skip(1)
+        });
+
+        self.points.first().copied().into_iter().chain(lines)
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Cell {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl Debug for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Air => ".",
+            Self::Rock => "#",
+            Self::Sand => "o",
+        };
+        write!(f, "{s}")
+    }
+}
+
+struct Polylines(Vec<Polyline>);
+
+impl FromStr for Polylines {
+    type Err = Report;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self(
+            s.lines()
+                .map(Polyline::from_str)
+                .collect::<Result<Vec<Polyline>>>()?,
+        ))
+    }
+}
+
+impl Polylines {
+    fn with(&self, polyline: Polyline) -> Self {
+        let mut inner = self.0.clone();
+        inner.push(polyline);
+        Self(inner)
+    }
+
+    fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.0
+            .iter()
+            .flat_map(|polyline| polyline.path_points())
+            .chain(std::iter::once(SPAWN_POINT))
+    }
+
+    fn bounds(&self) -> Bounds<Point> {
+        Bounds::from_points(self.points()).expect("at least the spawn point")
+    }
+
+    fn to_cave(&self) -> Result<Cave> {
+        let bounds = self.bounds();
+        let origin = bounds.min;
+        let height = bounds.height().try_into()?;
+        let width = bounds.width().try_into()?;
+
+        let mut cave = Cave {
+            origin,
+            grid: Grid::new(width, height, Cell::Air),
+        };
+
+        for p in self.points() {
+            *cave.cell_mut(p).unwrap() = Cell::Rock;
+        }
+
+        Ok(cave)
+    }
+}
+
+// The part of the cave that is mapped, where the top left cell of the grid is at the origin
+#[derive(Clone)]
+struct Cave {
+    origin: Point,
+    grid: Grid<Cell>,
+}
+
+impl FromStr for Cave {
+    type Err = Report;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        s.parse::<Polylines>()?.to_cave()
+    }
+}
+
+impl Debug for Cave {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.grid)
+    }
+}
+
+impl Cave {
+    fn pos(&self, p: Point) -> Option<Pos> {
+        let Point { x, y } = p - self.origin;
+        Some(Pos::new(y.try_into().ok()?, x.try_into().ok()?))
+    }
+
+    fn cell(&self, p: Point) -> Option<Cell> {
+        self.grid.get(self.pos(p)?).copied()
+    }
+
+    fn cell_mut(&mut self, p: Point) -> Option<&mut Cell> {
+        let pos = self.pos(p)?;
+        self.grid.get_mut(pos)
+    }
+
+    fn simulation(&self) -> Simulation {
+        Simulation {
+            filled: false,
+            grains: vec![SPAWN_POINT],
+            cave: self.to_owned(),
+            settled: 0,
+        }
+    }
+}
+
+struct Simulation {
+    filled: bool,
+    grains: Vec<Point>,
+    cave: Cave,
+    settled: usize,
+}
+
+impl Simulation {
+    fn step(&mut self) -> usize {
+        let mut grains = std::mem::take(&mut self.grains);
+
+        // Moves a grain on, and says whether it is done with, either settled or fallen into the void
+        let mut fall = |grain: &mut Point| -> bool {
+            if self.filled {
+                return true;
+            }
+
+            let down = *grain + Point { x: 0, y: 1 };
+            let down_left = *grain + Point { x: -1, y: 1 };
+            let down_right = *grain + Point { x: 1, y: 1 };
+            let options = [down, down_left, down_right];
+
+            if let Some(p) = options
+                .into_iter()
+                .find(|p| matches!(self.cave.cell(*p), Some(Cell::Air)))
+            {
+                *grain = p;
+                // Keep
+                return false;
+            };
+
+            if options.into_iter().any(|p| self.cave.cell(p).is_none()) {
+                // Remove
+                return true;
+            }
+
+            if self.cave.cell(*grain) == Some(Cell::Sand) {
+                self.filled = true;
+                return false;
+            }
+
+            self.settled += 1;
+            *self.cave.cell_mut(*grain).unwrap() = Cell::Sand;
+            // Remove
+            true
+        };
+
+        #[cfg(feature = "nightly")]
+        let _ = grains.drain_filter(|grain| fall(grain)).count();
+        #[cfg(not(feature = "nightly"))]
+        grains.retain_mut(|grain| !fall(grain));
+
+        self.grains = grains;
+        if !self.filled {
+            self.grains.push(SPAWN_POINT);
+        }
+
+        self.settled
+    }
+}
+
+pub struct Task {
+    polylines: Polylines,
+}
+
+impl Task {
+    pub fn parse(input: &str) -> Result<Self> {
+        let polylines = input.parse::<Polylines>()?;
+        Ok(Self { polylines })
+    }
+
+    pub fn sand_at_rest(&self) -> usize {
+        let cave = self.polylines.to_cave().unwrap();
+        self.count_sand(&cave, 100)
+    }
+
+    pub fn sand_with_floor(&self) -> usize {
+        let Bounds { min, max } = self.polylines.bounds();
+
+        // Include the floor as a very long polyline
+        let floor = Polyline {
+            points: vec![
+                Point::new(min.x - 10_000, max.y + 2),
+                Point::new(max.x + 10_000, max.y + 2),
+            ],
+        };
+
+        let cave = self.polylines.with(floor).to_cave().unwrap();
+        self.count_sand(&cave, 10_000)
+    }
+
+    fn count_sand(&self, cave: &Cave, steps: usize) -> usize {
+        self.settle(cave, steps).settled
+    }
+
+    fn settle(&self, cave: &Cave, steps: usize) -> Simulation {
+        let mut s = cave.simulation();
+        let mut curr = usize::MAX;
+
+        // TODO: Figure out a more reliable approach to determining when to exit this loop
+        while curr != s.settled {
+            curr = s.settled;
+            for _ in 0..steps {
+                s.step();
+            }
+        }
+
+        s
+    }
+}
+
+impl Solution for Task {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        Self::parse(input)
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(self.sand_at_rest())
+    }
+
+    fn part2(&self) -> Result<usize> {
+        Ok(self.sand_with_floor())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_from_str() {
+        let p = parse_point("5,5").unwrap();
+        assert_eq!(p, Point { x: 5, y: 5 });
+    }
+
+    #[test]
+    fn polyline_from_str() {
+        let Polyline { points } = Polyline::from_str("498,4 -> 498,6 -> 496,6").unwrap();
+        assert_eq!(
+            points,
+            vec![
+                Point { x: 498, y: 4 },
+                Point { x: 498, y: 6 },
+                Point { x: 496, y: 6 }
+            ]
+        );
+    }
+
+    #[test]
+    fn path_points() {
+        let p = Polyline::from_str("498,4 -> 498,6 -> 496,6").unwrap();
+        assert_eq!(
+            p.path_points().collect_vec(),
+            vec![
+                Point { x: 498, y: 4 },
+                Point { x: 498, y: 5 },
+                Point { x: 498, y: 6 },
+                Point { x: 497, y: 6 },
+                Point { x: 496, y: 6 }
+            ]
+        );
+    }
+
+    #[test]
+    fn task1() {
+        let input = include_str!("../data/example.txt");
+        let task = Task::parse(input).unwrap();
+        assert_eq!(task.sand_at_rest(), 24);
+    }
+
+    #[test]
+    fn task1_with_input() {
+        let input = include_str!("../data/input.txt");
+        let task = Task::parse(input).unwrap();
+        assert_eq!(task.sand_at_rest(), 979);
+    }
+
+    #[test]
+    fn task2() {
+        let input = include_str!("../data/example.txt");
+        let task = Task::parse(input).unwrap();
+        assert_eq!(task.sand_with_floor(), 93);
+    }
+}
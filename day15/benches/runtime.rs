@@ -0,0 +1 @@
+common::benchmarks!("day15", include_str!("../data/input.txt"), day15::Task);
@@ -0,0 +1,79 @@
+// Readings from `size` sensors, and four more that hide the distress beacon.  The beacon is put
+// somewhere inside the search area, and the four sensors sit diagonally away from it, beyond the
+// area, each reaching to one short of the beacon:
+//
+//   S . . . S
+//   . . . . .
+//   . . H . .
+//   . . . . .
+//   S . . . S
+//
+// Between them they cover the whole search area but the beacon.  The other sensors are scattered
+// about the area and stop short of the beacon too.  Their beacons are at the edge of what they
+// reach, but nothing keeps another beacon from being closer to one of them than its own.
+use crate::{Point, LENGTH};
+use common::generate::{Rng, SliceRandom, StdRng};
+use itertools::Itertools;
+
+fn reading(sensor: Point, beacon: Point) -> String {
+    format!(
+        "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+        sensor.x, sensor.y, beacon.x, beacon.y
+    )
+}
+
+// A point at the given distance from a point, in a random direction
+fn at_distance(rng: &mut StdRng, p: Point, distance: i64) -> Point {
+    let dx = rng.gen_range(-distance..=distance);
+    let dy = (distance - dx.abs()) * if rng.gen_bool(0.5) { 1 } else { -1 };
+    Point::new(p.x + dx, p.y + dy)
+}
+
+// The input, and the beacon hidden in a search area of the given length
+fn readings(rng: &mut StdRng, size: usize, length: i64) -> (String, Point) {
+    let hidden = Point::new(rng.gen_range(1..length), rng.gen_range(1..length));
+    let a = length + 1;
+
+    let corners = [(-a, -a), (a, -a), (-a, a), (a, a)].map(|(dx, dy)| {
+        let sensor = Point::new(hidden.x + dx, hidden.y + dy);
+        (sensor, at_distance(rng, sensor, 2 * a - 1))
+    });
+
+    let others = (0..size).filter_map(|_| {
+        let sensor = Point::new(rng.gen_range(0..=length), rng.gen_range(0..=length));
+        let distance = sensor.manhattan(hidden);
+        (distance > 1).then(|| {
+            let reach = rng.gen_range(1..distance);
+            (sensor, at_distance(rng, sensor, reach))
+        })
+    });
+
+    let mut readings = corners.into_iter().chain(others).collect_vec();
+    readings.shuffle(rng);
+
+    let input = readings
+        .into_iter()
+        .map(|(sensor, beacon)| reading(sensor, beacon))
+        .collect();
+    (input, hidden)
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    readings(rng, size, LENGTH).0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Task;
+
+    #[test]
+    fn hidden_beacon() {
+        for seed in 0..10 {
+            let (input, hidden) = readings(&mut common::generate::rng(seed), 10, 20);
+            let task = input.parse::<Task>().unwrap();
+            assert_eq!(task.hidden_beacon(20).unwrap(), hidden);
+            assert!(task.no_beacon(10) > 0);
+        }
+    }
+}
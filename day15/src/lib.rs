@@ -0,0 +1,195 @@
+#![cfg_attr(feature = "nightly", feature(binary_heap_into_iter_sorted))]
+
+use color_eyre::{eyre::eyre, Report, Result};
+use common::Solution;
+use itertools::Itertools;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::{collections::BinaryHeap, str::FromStr};
+
+pub mod generate;
+mod parser;
+pub use parser::Point;
+use parser::{Range, Reading};
+
+struct Readings(Vec<Reading>);
+
+impl FromStr for Readings {
+    type Err = Report;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self(
+            s.lines()
+                .map(str::trim)
+                .map(|line| parser::reading(s, line))
+                .collect::<Result<Vec<Reading>>>()?,
+        ))
+    }
+}
+
+impl Readings {
+    fn iter(&self) -> impl Iterator<Item = &Reading> + '_ {
+        self.0.iter()
+    }
+}
+
+pub struct Task {
+    readings: Readings,
+}
+
+impl FromStr for Task {
+    type Err = Report;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let readings = s.parse::<Readings>()?;
+        Ok(Self { readings })
+    }
+}
+
+pub const LENGTH: i64 = 4000000;
+
+pub fn tuning_frequency(beacon: Point) -> i64 {
+    beacon.x * 4_000_000 + beacon.y
+}
+
+impl Task {
+    fn clamped_ranges(&self, y: i64, x_range: Range) -> impl Iterator<Item = Range> {
+        self.ranges(y).filter_map(move |r| {
+            let r = r & x_range.clone();
+            if r.start() > r.end() {
+                None
+            } else {
+                Some(r)
+            }
+        })
+    }
+
+    fn ranges(&self, y: i64) -> impl Iterator<Item = Range> {
+        let mut ranges: BinaryHeap<Range> = BinaryHeap::new();
+
+        for reading in self.readings.iter() {
+            if let Some(curr) = reading.range_at_y(y) {
+                ranges.push(curr);
+            }
+        }
+
+        // Largest first, either way
+        #[cfg(feature = "nightly")]
+        let sorted = ranges.into_iter_sorted();
+        #[cfg(not(feature = "nightly"))]
+        let sorted = ranges.into_sorted_vec().into_iter().rev();
+
+        sorted.coalesce(|a, b| {
+            if a.overlap(&b) {
+                Ok(a | b)
+            } else {
+                Err((a, b))
+            }
+        })
+    }
+
+    pub fn no_beacon(&self, y: i64) -> i64 {
+        let ranges = self.ranges(y);
+        self.count_elements(ranges)
+    }
+
+    fn count_elements<R>(&self, ranges: R) -> i64
+    where
+        R: Iterator<Item = Range>,
+    {
+        ranges.map(|r| r.end() - r.start()).sum::<i64>()
+    }
+
+    pub fn hidden_beacon(&self, length: i64) -> Result<Point> {
+        let x_range = Range::new(0, length);
+        let beacon = |y| {
+            self.clamped_ranges(y, x_range.clone())
+                .nth(1)
+                .map(|r| Point::new(r.start() - 1, y))
+        };
+
+        // The row nearest the top, either way
+        #[cfg(feature = "parallel")]
+        let beacon = (0..=length).into_par_iter().find_map_first(beacon);
+        #[cfg(not(feature = "parallel"))]
+        let beacon = (0..=length).find_map(beacon);
+
+        beacon.ok_or(eyre!("no beacon found"))
+    }
+}
+
+impl Solution for Task {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self> {
+        input.parse()
+    }
+
+    fn part1(&self) -> Result<i64> {
+        Ok(self.no_beacon(2_000_000))
+    }
+
+    fn part2(&self) -> Result<i64> {
+        Ok(tuning_frequency(self.hidden_beacon(LENGTH)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn readings() -> Readings {
+        let input = include_str!("../data/example.txt");
+        input.parse::<Readings>().unwrap()
+    }
+
+    #[test]
+    fn parsing() {
+        let readings = readings();
+        assert_eq!(readings.0.len(), 14);
+    }
+
+    #[test]
+    fn no_beacon() {
+        let input = include_str!("../data/example.txt");
+        let task = input.parse::<Task>().unwrap();
+        assert_eq!(task.no_beacon(10), 26);
+    }
+
+    #[test]
+    fn no_beacon_with_input() {
+        let input = include_str!("../data/input.txt");
+        let task = input.parse::<Task>().unwrap();
+        assert_eq!(task.no_beacon(2_000_000), 5461729);
+    }
+
+    #[test]
+    fn coverage_at() {
+        let input = include_str!("../data/example.txt");
+        let task = input.parse::<Task>().unwrap();
+        let merged = task.ranges(11).collect_vec();
+        assert_eq!(merged, vec![Range::new(-3, 13), Range::new(15, 25)]);
+    }
+
+    #[test]
+    fn tuning_frequency() {
+        let input = include_str!("../data/example.txt");
+        let task = input.parse::<Task>().unwrap();
+        assert_eq!(
+            super::tuning_frequency(task.hidden_beacon(20).unwrap()),
+            56000011
+        );
+    }
+
+    // #[test]
+    #[allow(unused)]
+    fn tuning_frequency_with_input() {
+        let input = include_str!("../data/input.txt");
+        let task = input.parse::<Task>().unwrap();
+        assert_eq!(
+            super::tuning_frequency(task.hidden_beacon(LENGTH).unwrap()),
+            10621647166538
+        );
+    }
+}
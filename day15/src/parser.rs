@@ -1,32 +1,15 @@
 use std::ops::{BitAnd, BitOr, RangeInclusive};
 
-use color_eyre::{eyre::eyre, Result};
+use color_eyre::Result;
+use common::parse::{context, finish, IResult};
+use geometry::Vec2;
 use nom::{
     bytes::complete::tag,
     combinator::{all_consuming, map},
-    sequence::{preceded, separated_pair},
-    Finish, IResult,
+    sequence::{pair, preceded, separated_pair},
 };
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-pub struct Point {
-    x: i64,
-    y: i64,
-}
-
-impl Point {
-    pub fn new(x: i64, y: i64) -> Self {
-        Self { x, y }
-    }
-
-    pub fn manhattan_distance(&self, other: &Self) -> i64 {
-        (self.x - other.x).abs() + (self.y - other.y).abs()
-    }
-
-    pub fn tuning_frequency(&self) -> i64 {
-        self.x * 4_000_000 + self.y
-    }
-}
+pub type Point = Vec2<i64>;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Range(pub RangeInclusive<i64>);
@@ -91,7 +74,7 @@ impl From<(i64, i64, i64, i64)> for Reading {
     fn from((sx, sy, bx, by): (i64, i64, i64, i64)) -> Self {
         let sensor = Point::new(sx, sy);
         let beacon = Point::new(bx, by);
-        let distance = sensor.manhattan_distance(&beacon);
+        let distance = sensor.manhattan(beacon);
 
         Self {
             sensor,
@@ -126,49 +109,65 @@ fn parse_point(s: &str) -> IResult<&str, Point> {
 
 pub fn parse_reading(s: &str) -> IResult<&str, Reading> {
     map(
-        separated_pair(
-            preceded(tag("Sensor at "), parse_point),
-            tag(": closest beacon is at "),
-            parse_point,
+        pair(
+            context("a sensor", preceded(tag("Sensor at "), parse_point)),
+            context(
+                "a beacon",
+                preceded(tag(": closest beacon is at "), parse_point),
+            ),
         ),
         |(sensor, beacon)| Reading {
             sensor,
             beacon,
-            distance: sensor.manhattan_distance(&beacon),
+            distance: sensor.manhattan(beacon),
         },
     )(s)
 }
 
-pub fn reading(input: &str) -> Result<Reading> {
-    let reading = all_consuming(parse_reading)(input)
-        .finish()
-        .or(Err(eyre!("problem parsing reading")))?
-        .1;
-    Ok(reading)
+// Parses a line of the input
+pub fn reading(input: &str, line: &str) -> Result<Reading> {
+    finish(input, all_consuming(parse_reading)(line))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn parse_line(line: &str) -> Reading {
+        reading(line, line).unwrap()
+    }
+
     #[test]
     fn simple_case() {
-        let r = reading("Sensor at x=2, y=18: closest beacon is at x=-2, y=15").unwrap();
+        let r = parse_line("Sensor at x=2, y=18: closest beacon is at x=-2, y=15");
         assert_eq!(r.sensor, Point::new(2, 18));
         assert_eq!(r.beacon, Point::new(-2, 15));
     }
 
     #[test]
     fn range() {
-        let r = reading("Sensor at x=8, y=7: closest beacon is at x=2, y=10").unwrap();
+        let r = parse_line("Sensor at x=8, y=7: closest beacon is at x=2, y=10");
         assert_eq!(r.range_at_y(10), Some(Range::new(2, 14)));
 
-        let r = reading("Sensor at x=2, y=18: closest beacon is at x=-2, y=15").unwrap();
+        let r = parse_line("Sensor at x=2, y=18: closest beacon is at x=-2, y=15");
         assert_eq!(r.range_at_y(15), Some(Range::new(-2, 6)),);
         assert_eq!(r.range_at_y(16), Some(Range::new(-3, 7)));
         assert_eq!(r.range_at_y(100), None);
     }
 
+    #[test]
+    fn parse_errors() {
+        let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon at x=10, y=16";
+        let line = input.lines().nth(1).unwrap();
+
+        let err = reading(input, line).err().unwrap();
+        let err = err.downcast_ref::<common::parse::ParseError>().unwrap();
+        assert_eq!((err.line, err.column), (2, 20));
+        assert_eq!(err.expected, "a beacon");
+        assert_eq!(err.source_line, line);
+    }
+
     #[test]
     fn merge() {
         assert_eq!(Range::new(0, 1) | Range::new(1, 2), Range::new(0, 2));
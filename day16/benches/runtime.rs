@@ -0,0 +1 @@
+common::benchmarks!("day16", include_str!("../data/input.txt"), day16::Task);
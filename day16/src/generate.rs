@@ -0,0 +1,73 @@
+// Scans of `size` valves with a flow, up to fifteen of them, and as many again that are stuck, as
+// well as AA where the search starts.  The tunnels join every valve to every other one: they are a
+// random tree, with a few more tunnels added.
+use common::generate::{Rng, SliceRandom, StdRng};
+use itertools::Itertools;
+use std::collections::BTreeSet;
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let flowing = size.min(15);
+
+    let mut names = (b'A'..=b'Z')
+        .cartesian_product(b'A'..=b'Z')
+        .map(|(a, b)| String::from_utf8(vec![a, b]).unwrap())
+        .filter(|name| name != "AA")
+        .collect_vec();
+    names.shuffle(rng);
+    names.truncate(2 * flowing);
+    names.insert(0, "AA".to_owned());
+
+    let flows = (0..names.len())
+        .map(|i| match i {
+            i if i > 0 && i <= flowing => rng.gen_range(1..=25),
+            _ => 0,
+        })
+        .collect_vec();
+
+    let mut links = vec![BTreeSet::new(); names.len()];
+    let mut link = |a: usize, b: usize| {
+        links[a].insert(b);
+        links[b].insert(a);
+    };
+
+    let mut order = (0..names.len()).collect_vec();
+    order.shuffle(rng);
+    for i in 1..order.len() {
+        let parent = order[rng.gen_range(0..i)];
+        link(order[i], parent);
+    }
+    for _ in 0..names.len() / 2 {
+        let (a, b) = (rng.gen_range(0..names.len()), rng.gen_range(0..names.len()));
+        if a != b {
+            link(a, b);
+        }
+    }
+
+    let mut lines = (0..names.len())
+        .map(|i| {
+            let tunnels = match links[i].len() {
+                1 => format!(
+                    "tunnel leads to valve {}",
+                    names[*links[i].first().unwrap()]
+                ),
+                _ => format!(
+                    "tunnels lead to valves {}",
+                    links[i].iter().map(|&j| &names[j]).join(", ")
+                ),
+            };
+            format!("Valve {} has flow rate={}; {tunnels}\n", names[i], flows[i])
+        })
+        .collect_vec();
+    lines.shuffle(rng);
+    lines.concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<crate::Task>(input, 8, 0..10).unwrap();
+    }
+}
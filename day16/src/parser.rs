@@ -1,14 +1,14 @@
 use std::fmt::Debug;
 
-use color_eyre::{eyre::eyre, Report, Result};
+use color_eyre::{Report, Result};
+use common::parse::{context, finish, IResult};
 use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::{alphanumeric1, multispace1},
-    combinator::{all_consuming, map},
+    combinator::{all_consuming, cut, map},
     multi::separated_list1,
     sequence::{preceded, tuple},
-    Finish, IResult,
 };
 
 #[derive(Clone, Copy, Eq, Hash, PartialEq)]
@@ -57,11 +57,20 @@ fn parse_single_valve(s: &str) -> IResult<&str, Vec<String>> {
 
 fn parse_reading(s: &str) -> IResult<&str, Valve> {
     map(
-        tuple((
-            preceded(tag("Valve "), alphanumeric1),
-            preceded(tag(" has flow rate="), nom::character::complete::u8),
-            preceded(tag("; "), alt((parse_valve_list, parse_single_valve))),
-        )),
+        preceded(
+            tag("Valve "),
+            cut(tuple((
+                context("a valve name", alphanumeric1),
+                context(
+                    "a flow rate",
+                    preceded(tag(" has flow rate="), nom::character::complete::u8),
+                ),
+                context(
+                    "the tunnels to other valves",
+                    preceded(tag("; "), alt((parse_valve_list, parse_single_valve))),
+                ),
+            ))),
+        ),
         |(name, flow, links)| Valve {
             name: name.to_owned(),
             flow,
@@ -71,11 +80,8 @@ fn parse_reading(s: &str) -> IResult<&str, Valve> {
 }
 
 pub fn parse(input: &str) -> Result<Valves> {
-    let readings = all_consuming(separated_list1(multispace1, parse_reading))(input.trim())
-        .finish()
-        .or(Err(eyre!("failed to parse input")))?
-        .1;
-    Ok(readings)
+    let readings = all_consuming(separated_list1(multispace1, parse_reading))(input.trim());
+    finish(input, readings)
 }
 
 #[cfg(test)]
@@ -103,4 +109,27 @@ mod tests {
         assert_eq!(valve.flow, 21);
         assert_eq!(valve.links, vec!["II"]);
     }
+
+    #[test]
+    fn parse_errors() {
+        let error = |input: &str| {
+            let err = parse(input).unwrap_err();
+            let err = err.downcast_ref::<common::parse::ParseError>().unwrap();
+            (err.line, err.column, err.expected.clone())
+        };
+
+        let first = "Valve AA has flow rate=0; tunnels lead to valves DD, BB\n";
+        assert_eq!(
+            error(&format!(
+                "{first}Valve BB has flow rate=x; tunnel leads to valve AA"
+            )),
+            (2, 24, "a number".into())
+        );
+        assert_eq!(
+            error(&format!(
+                "{first}Valve BB has flow rate=3; tunnels go to valve AA"
+            )),
+            (2, 27, "the tunnels to other valves".into())
+        );
+    }
 }
@@ -0,0 +1,105 @@
+// The 2022 rocks of the first part falling into the chamber, pushed by a jet of gas and then moved
+// down at each step.  The falling rock is drawn as @ and settled rock as #, with the top of the
+// tower at the top of the frame.
+use crate::cycles::{Chamber, Direction, Rock, State, Task};
+use color_eyre::Result;
+use common::animate::Animation;
+use geometry::Vec2;
+
+const NUM_ROCKS: usize = 2022;
+
+struct Rocks {
+    jets: Vec<Direction>,
+    jet: usize,
+    chamber: Chamber,
+    rock: Option<Rock>,
+    settled: usize,
+}
+
+impl Rocks {
+    fn drop_rock(&mut self) {
+        self.rock = (self.settled < NUM_ROCKS).then(|| Rock {
+            shape: State::SHAPES[self.settled % State::NUM_SHAPES],
+            bottom_left: Vec2::new(2, self.chamber.max_i + 4),
+        });
+    }
+}
+
+impl Animation for Rocks {
+    fn step(&mut self) -> bool {
+        let Some(rock) = &mut self.rock else {
+            return false;
+        };
+
+        let falling = rock.step(&self.chamber, self.jets[self.jet]);
+        self.jet = (self.jet + 1) % self.jets.len();
+
+        if !falling {
+            self.chamber.insert(rock.clone());
+            self.settled += 1;
+            self.drop_rock();
+        }
+
+        true
+    }
+
+    fn frame(&self) -> String {
+        let rock = self
+            .rock
+            .iter()
+            .flat_map(|rock| rock.points())
+            .collect::<Vec<_>>();
+        let top = rock.iter().map(|p| p.y).fold(self.chamber.max_i, i64::max);
+
+        let mut frame = (0..=top)
+            .rev()
+            .flat_map(|y| {
+                let cells = (0..7).map(move |x| Vec2::new(x, y)).map(|p| {
+                    if rock.contains(&p) {
+                        '@'
+                    } else if self.chamber.is_available(p) {
+                        '.'
+                    } else {
+                        '#'
+                    }
+                });
+                ['|'].into_iter().chain(cells).chain(['|', '\n'])
+            })
+            .collect::<String>();
+
+        frame.push_str("+-------+\n");
+        frame
+    }
+}
+
+pub fn animation(input: &str) -> Result<Box<dyn Animation>> {
+    let task = input.parse::<Task>()?;
+
+    let mut rocks = Rocks {
+        jets: task.jets,
+        jet: 0,
+        chamber: Chamber::default(),
+        rock: None,
+        settled: 0,
+    };
+    rocks.drop_rock();
+
+    Ok(Box::new(rocks))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let mut rocks = animation(include_str!("../data/example.txt")).unwrap();
+        assert_eq!(
+            rocks.frame(),
+            "|..@@@@.|\n|.......|\n|.......|\n|.......|\n+-------+\n"
+        );
+
+        while rocks.step() {}
+        assert_eq!(rocks.frame().lines().count(), 3068 + 1);
+    }
+}
@@ -1,5 +1,7 @@
 use ahash::RandomState;
-use color_eyre::{self, Report, Result};
+use color_eyre::{self, eyre::eyre, Report, Result};
+use common::Solution;
+use geometry::Vec2;
 use std::{
     collections::{BTreeMap, HashSet},
     fmt::{Debug, Write},
@@ -7,7 +9,8 @@ use std::{
 };
 
 type Int = i16;
-type Point = (Int, Int);
+// x is the column and y the height above the floor
+type Point = Vec2<Int>;
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 enum Direction {
@@ -25,26 +28,59 @@ enum Shape {
 }
 
 impl Shape {
+    const HORIZONTAL: [Point; 4] = [
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(2, 0),
+        Point::new(3, 0),
+    ];
+    const PLUS: [Point; 5] = [
+        Point::new(1, 0),
+        Point::new(0, 1),
+        Point::new(1, 1),
+        Point::new(2, 1),
+        Point::new(1, 2),
+    ];
+    const REVERSE_L: [Point; 5] = [
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(2, 0),
+        Point::new(2, 1),
+        Point::new(2, 2),
+    ];
+    const VERTICAL: [Point; 4] = [
+        Point::new(0, 0),
+        Point::new(0, 1),
+        Point::new(0, 2),
+        Point::new(0, 3),
+    ];
+    const SQUARE: [Point; 4] = [
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(0, 1),
+        Point::new(1, 1),
+    ];
+
     fn points(&self) -> &[Point] {
         match self {
-            Self::Horizontal => &[(0, 0), (0, 1), (0, 2), (0, 3)],
-            Self::Plus => &[(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)],
-            Self::ReverseL => &[(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)],
-            Self::Vertical => &[(0, 0), (1, 0), (2, 0), (3, 0)],
-            Self::Square => &[(0, 0), (0, 1), (1, 0), (1, 1)],
+            Self::Horizontal => &Self::HORIZONTAL,
+            Self::Plus => &Self::PLUS,
+            Self::ReverseL => &Self::REVERSE_L,
+            Self::Vertical => &Self::VERTICAL,
+            Self::Square => &Self::SQUARE,
         }
     }
 
-    fn shift_horizontal(&self, (i, j): Point, dj_delta: Int) -> impl Iterator<Item = Point> + '_ {
+    fn shift_horizontal(&self, p: Point, dj_delta: Int) -> impl Iterator<Item = Point> + '_ {
         self.points()
             .iter()
-            .map(move |(di, dj)| (i + di, j + dj + dj_delta))
+            .map(move |&d| p + d + Point::new(dj_delta, 0))
     }
 
-    fn shift_vertical(&self, (i, j): Point, di_delta: Int) -> impl Iterator<Item = Point> + '_ {
+    fn shift_vertical(&self, p: Point, di_delta: Int) -> impl Iterator<Item = Point> + '_ {
         self.points()
             .iter()
-            .map(move |(di, dj)| (i + di + di_delta, j + dj))
+            .map(move |&d| p + d + Point::new(0, di_delta))
     }
 }
 
@@ -61,8 +97,8 @@ impl Debug for Chamber {
         let mut rows = BTreeMap::<Int, [Point; COLS]>::new();
 
         for point in &self.points {
-            let row = rows.entry(-point.0).or_insert([(-1, -1); COLS]);
-            row[point.1 as usize] = *point;
+            let row = rows.entry(-point.y).or_insert([Point::new(-1, -1); COLS]);
+            row[point.x as usize] = *point;
         }
 
         f.write_str("\n\n|.......|\n")?;
@@ -70,7 +106,7 @@ impl Debug for Chamber {
         for (_i, row) in rows.iter() {
             f.write_char('|')?;
             for p in row {
-                let c = if p.0 > -1 { '#' } else { '.' };
+                let c = if p.y > -1 { '#' } else { '.' };
                 f.write_char(c)?;
             }
             f.write_str("|\n")?;
@@ -90,14 +126,14 @@ impl Chamber {
     }
 
     fn is_available(&self, p: &Point) -> bool {
-        (p.1 >= 0 && (p.1 as usize) < COLS) && p.0 > 0 && !self.points.contains(p)
+        (p.x >= 0 && (p.x as usize) < COLS) && p.y > 0 && !self.points.contains(p)
     }
 
     fn insert(&mut self, rock: Rock) {
         let points = rock.points().collect::<Vec<_>>();
         let mut height = 0;
 
-        for &(i, j) in &points {
+        for &Vec2 { x: j, y: i } in &points {
             let j = j as usize;
             self.max_i[j] = self.max_i[j].max(i);
             height = height.max(self.max_i[j]);
@@ -122,12 +158,12 @@ impl Rock {
     fn step(&mut self, chamber: &Chamber, dj: Direction) -> bool {
         // Can we move laterally?
         if self.horizontal_clearance(chamber, dj as Int) {
-            self.bottom_left.1 += dj as Int;
+            self.bottom_left.x += dj as Int;
         }
 
         // Can we move down?
         if self.vertical_clearance(chamber, -1) {
-            self.bottom_left.0 -= 1;
+            self.bottom_left.y -= 1;
             return true;
         }
 
@@ -147,11 +183,8 @@ impl Rock {
     }
 
     fn points(&self) -> impl Iterator<Item = Point> + '_ {
-        let (i, j) = self.bottom_left;
-        self.shape
-            .points()
-            .iter()
-            .map(move |&(di, dj)| (i + di, j + dj))
+        let p = self.bottom_left;
+        self.shape.points().iter().map(move |&d| p + d)
     }
 }
 
@@ -167,11 +200,14 @@ impl FromStr for Task {
             .trim()
             .chars()
             .map(|c| match c {
-                '<' => Direction::Left,
-                '>' => Direction::Right,
-                _ => panic!("unexpected character"),
+                '<' => Ok(Direction::Left),
+                '>' => Ok(Direction::Right),
+                c => Err(eyre!("unexpected character in the jet pattern: {c:?}")),
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>>>()?;
+        if gusts.is_empty() {
+            return Err(eyre!("the jet pattern is empty"));
+        }
 
         Ok(Self { gusts })
     }
@@ -196,7 +232,7 @@ impl Task {
         for i in 0..num_rocks {
             let mut rock = Rock {
                 shape: Self::SHAPES[i % Self::NUM_SHAPES],
-                bottom_left: (chamber.height() + 4, 2),
+                bottom_left: Point::new(2, chamber.height() + 4),
             };
 
             loop {
@@ -219,6 +255,23 @@ pub fn parse(input: &str) -> Result<Task> {
     input.parse::<Task>()
 }
 
+impl Solution for Task {
+    type Part1 = Int;
+    type Part2 = Int;
+
+    fn parse(input: &str) -> Result<Self> {
+        parse(input)
+    }
+
+    fn part1(&self) -> Result<Int> {
+        Ok(self.height_of_tower(2022))
+    }
+
+    fn part2(&self) -> Result<Int> {
+        Err(eyre!("this implementation is too slow to drop 1e12 rocks"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,22 +1,26 @@
-use color_eyre::{self, Report, Result};
+use color_eyre::{self, eyre::eyre, Report, Result};
+use common::Solution;
+use geometry::Vec2;
+use grid::{Grid, Pos};
 use std::{
     fmt::{Debug, Write},
     str::FromStr,
 };
 
 type Int = i64;
-type Point = (Int, Int);
+// x is the column and y the height above the floor
+type Point = Vec2<Int>;
 
 const CHAMBER_WIDTH: usize = 7;
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-enum Direction {
+pub(crate) enum Direction {
     Left = -1,
     Right = 1,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
-enum Shape {
+pub(crate) enum Shape {
     Horizontal,
     Plus,
     ReverseL,
@@ -25,60 +29,87 @@ enum Shape {
 }
 
 impl Shape {
+    const HORIZONTAL: [Point; 4] = [
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(2, 0),
+        Point::new(3, 0),
+    ];
+    const PLUS: [Point; 5] = [
+        Point::new(1, 0),
+        Point::new(0, 1),
+        Point::new(1, 1),
+        Point::new(2, 1),
+        Point::new(1, 2),
+    ];
+    const REVERSE_L: [Point; 5] = [
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(2, 0),
+        Point::new(2, 1),
+        Point::new(2, 2),
+    ];
+    const VERTICAL: [Point; 4] = [
+        Point::new(0, 0),
+        Point::new(0, 1),
+        Point::new(0, 2),
+        Point::new(0, 3),
+    ];
+    const SQUARE: [Point; 4] = [
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(0, 1),
+        Point::new(1, 1),
+    ];
+
     fn points(&self) -> &[Point] {
         match self {
-            Self::Horizontal => &[(0, 0), (0, 1), (0, 2), (0, 3)],
-            Self::Plus => &[(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)],
-            Self::ReverseL => &[(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)],
-            Self::Vertical => &[(0, 0), (1, 0), (2, 0), (3, 0)],
-            Self::Square => &[(0, 0), (0, 1), (1, 0), (1, 1)],
+            Self::Horizontal => &Self::HORIZONTAL,
+            Self::Plus => &Self::PLUS,
+            Self::ReverseL => &Self::REVERSE_L,
+            Self::Vertical => &Self::VERTICAL,
+            Self::Square => &Self::SQUARE,
         }
     }
 
     fn shift_horizontal(&self, p: Point, dj_delta: Int) -> impl Iterator<Item = Point> + '_ {
         self.points()
             .iter()
-            .map(move |(di, dj)| (p.0 + di, p.1 + dj + dj_delta))
+            .map(move |&d| p + d + Point::new(dj_delta, 0))
     }
 
     fn shift_vertical(&self, p: Point, di_delta: Int) -> impl Iterator<Item = Point> + '_ {
         self.points()
             .iter()
-            .map(move |(di, dj)| (p.0 + di + di_delta, p.1 + dj))
+            .map(move |&d| p + d + Point::new(0, di_delta))
     }
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq)]
 enum Cell {
     Empty,
     SettledRock,
 }
 
-pub struct Row([Cell; CHAMBER_WIDTH]);
-
-impl Row {
-    const EMPTY: [Cell; CHAMBER_WIDTH] = [Cell::Empty; CHAMBER_WIDTH];
-
-    fn new() -> Self {
-        Self(Self::EMPTY)
-    }
-}
-
-impl Debug for Row {
+impl Debug for Cell {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for cell in self.0 {
-            let c = match cell {
-                Cell::Empty => '.',
-                Cell::SettledRock => '#',
-            };
-            f.write_char(c)?;
+        match self {
+            Cell::Empty => f.write_char('.'),
+            Cell::SettledRock => f.write_char('#'),
         }
-        Ok(())
     }
 }
 
-struct Chamber {
-    pub rows: Vec<Row>,
+// A bit for each empty cell of a row, the first column being the lowest bit
+fn empty(row: &[Cell]) -> u8 {
+    (0..CHAMBER_WIDTH)
+        .filter(|&j| row[j] == Cell::Empty)
+        .fold(0, |mask, j| mask | 1 << j)
+}
+
+pub(crate) struct Chamber {
+    // Row i of the grid is i above the floor, so the chamber is upside down in it
+    rows: Grid<Cell>,
     pub max_i_by_col: [Int; CHAMBER_WIDTH],
     pub max_i: Int,
 }
@@ -86,8 +117,10 @@ struct Chamber {
 impl Debug for Chamber {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str("\n|-------|\n")?;
-        for row in self.rows.iter().rev() {
-            writeln!(f, "|{:?}|", row)?;
+        if let Ok(grid) = self.grid() {
+            for line in format!("{grid:?}").lines() {
+                writeln!(f, "|{line}|")?;
+            }
         }
         f.write_str("+-------+\n")
     }
@@ -96,7 +129,7 @@ impl Debug for Chamber {
 impl Default for Chamber {
     fn default() -> Self {
         Self {
-            rows: Vec::with_capacity(4096),
+            rows: Grid::new(CHAMBER_WIDTH, 0, Cell::Empty),
             max_i_by_col: [-1; CHAMBER_WIDTH],
             max_i: -1,
         }
@@ -104,8 +137,14 @@ impl Default for Chamber {
 }
 
 impl Chamber {
-    fn is_available(&self, p: Point) -> bool {
-        let (i, j) = p;
+    // The rows of the chamber from the top down
+    fn grid(&self) -> Result<Grid<Cell>> {
+        let height = self.rows.height();
+        Grid::from_rows((0..height).rev().map(|i| self.rows.row(i).to_vec()))
+    }
+
+    pub(crate) fn is_available(&self, p: Point) -> bool {
+        let Vec2 { x: j, y: i } = p;
 
         if j < 0 {
             return false;
@@ -123,41 +162,69 @@ impl Chamber {
 
         // If i goes beyond the current capacity of the chamber, there are no obstructions, and the
         // block can be placed here, assuming additional capacity is added.
-        if i >= self.rows.len() {
+        if i >= self.rows.height() {
             return true;
         }
 
-        self.rows[i].0.get(j) == Some(&Cell::Empty)
+        self.rows[Pos::new(i, j)] == Cell::Empty
     }
 
     fn set(&mut self, p: Point, next: Cell) {
-        debug_assert!(p.0 >= 0);
-        let i = p.0 as usize;
+        debug_assert!(p.y >= 0);
+        let i = p.y as usize;
 
-        debug_assert!((0..7).contains(&p.1));
+        debug_assert!((0..7).contains(&p.x));
 
-        if i >= self.rows.len() {
-            for _ in 0..10 {
-                self.rows.push(Row::new());
-            }
+        let height = self.rows.height();
+        if i >= height {
+            self.rows.insert_rows(height, 10, Cell::Empty);
         }
 
-        let cell = self.rows[i]
-            .0
-            .get_mut(p.1 as usize)
-            .expect("p.1 within column bounds");
+        let cell = self
+            .rows
+            .get_mut(Pos::new(i, p.x as usize))
+            .expect("p.x within column bounds");
 
         debug_assert_eq!(*cell, Cell::Empty, "tried to overwrite an existing rock");
 
         *cell = next;
     }
 
-    fn insert(&mut self, rock: Rock) {
+    // The empty cells that a falling rock could still get to, a bit per column and a row at a time
+    // down from the top.  Rocks only move sideways and down, so each row is reached from the one
+    // above.  Two chambers with the same surface go on the same way, which the heights of the
+    // columns don't tell, as rocks can be blown in under an overhang.
+    fn surface(&self) -> Vec<u8> {
+        let mut surface = vec![];
+        let mut reached = u8::MAX;
+
+        for i in (0..=self.max_i).rev() {
+            let empty = empty(self.rows.row(i as usize));
+            let mut next = reached & empty;
+            loop {
+                let wider = (next | next << 1 | next >> 1) & empty;
+                if wider == next {
+                    break;
+                }
+                next = wider;
+            }
+
+            if next == 0 {
+                break;
+            }
+            surface.push(next);
+            reached = next;
+        }
+
+        surface
+    }
+
+    pub(crate) fn insert(&mut self, rock: Rock) {
         let mut max_i = -1;
 
         for p in rock.points() {
-            let j = p.1 as usize;
-            self.max_i_by_col[j] = self.max_i_by_col[j].max(p.0);
+            let j = p.x as usize;
+            self.max_i_by_col[j] = self.max_i_by_col[j].max(p.y);
             max_i = max_i.max(self.max_i_by_col[j]);
             self.set(p, Cell::SettledRock);
         }
@@ -167,21 +234,21 @@ impl Chamber {
 }
 
 #[derive(Clone, Debug)]
-struct Rock {
-    shape: Shape,
-    bottom_left: Point,
+pub(crate) struct Rock {
+    pub shape: Shape,
+    pub bottom_left: Point,
 }
 
 impl Rock {
-    fn step(&mut self, chamber: &Chamber, dj: Direction) -> bool {
+    pub(crate) fn step(&mut self, chamber: &Chamber, dj: Direction) -> bool {
         // Can we move laterally?
         if self.horizontal_clearance(chamber, dj as Int) {
-            self.bottom_left.1 += dj as Int;
+            self.bottom_left.x += dj as Int;
         }
 
         // Can we move down?
         if self.vertical_clearance(chamber, -1) {
-            self.bottom_left.0 -= 1;
+            self.bottom_left.y -= 1;
             return true;
         }
 
@@ -200,18 +267,15 @@ impl Rock {
             .all(|p| chamber.is_available(p))
     }
 
-    fn points(&self) -> impl Iterator<Item = Point> + '_ {
+    pub(crate) fn points(&self) -> impl Iterator<Item = Point> + '_ {
         let p = self.bottom_left;
-        self.shape
-            .points()
-            .iter()
-            .map(move |&(di, dj)| (p.0 + di, p.1 + dj))
+        self.shape.points().iter().map(move |&d| p + d)
     }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 struct StateKey {
-    relative_heights: [Int; CHAMBER_WIDTH],
+    surface: Vec<u8>,
     rock_index: usize,
     jet: usize,
 }
@@ -223,7 +287,7 @@ struct StateValue {
 }
 
 #[derive(Debug, Default)]
-struct State {
+pub(crate) struct State {
     rock: Option<Rock>,
     curr_rock: usize,
     jet: usize,
@@ -237,9 +301,9 @@ struct State {
 }
 
 impl State {
-    const NUM_SHAPES: usize = 5;
+    pub(crate) const NUM_SHAPES: usize = 5;
 
-    const SHAPES: [Shape; Self::NUM_SHAPES] = [
+    pub(crate) const SHAPES: [Shape; Self::NUM_SHAPES] = [
         Shape::Horizontal,
         Shape::Plus,
         Shape::ReverseL,
@@ -265,7 +329,7 @@ impl State {
 
         let mut rock = Rock {
             shape: Self::SHAPES[rock_index],
-            bottom_left: (self.chamber.max_i + 4, 2),
+            bottom_left: Point::new(2, self.chamber.max_i + 4),
         };
         self.rock = Some(rock.clone());
 
@@ -289,15 +353,8 @@ impl State {
     }
 
     fn check_cycle(&mut self, rock_index: usize) {
-        let mut relative_heights = self.chamber.max_i_by_col;
-        let lowest = relative_heights.iter().copied().min().unwrap();
-
-        for h in &mut relative_heights {
-            *h -= lowest;
-        }
-
         let state_key = StateKey {
-            relative_heights,
+            surface: self.chamber.surface(),
             rock_index,
             jet: self.jet,
         };
@@ -321,7 +378,7 @@ impl State {
 }
 
 pub struct Task {
-    jets: Vec<Direction>,
+    pub(crate) jets: Vec<Direction>,
 }
 
 impl FromStr for Task {
@@ -332,11 +389,14 @@ impl FromStr for Task {
             .trim()
             .chars()
             .map(|c| match c {
-                '<' => Direction::Left,
-                '>' => Direction::Right,
-                _ => panic!("unexpected character"),
+                '<' => Ok(Direction::Left),
+                '>' => Ok(Direction::Right),
+                c => Err(eyre!("unexpected character in the jet pattern: {c:?}")),
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>>>()?;
+        if gusts.is_empty() {
+            return Err(eyre!("the jet pattern is empty"));
+        }
 
         Ok(Self { jets: gusts })
     }
@@ -347,6 +407,16 @@ impl Task {
         self.state_at(num_rocks).height()
     }
 
+    // Whether the tower falls into a cycle within `num_rocks` rocks.  It never does if the jets keep
+    // the rocks out of a column, as the surface then goes all the way down to the floor.
+    pub fn settles_within(&self, num_rocks: usize) -> bool {
+        let mut state = State::new(num_rocks, self.jets.clone());
+        while !state.done() && !state.cycle_found {
+            state = state.next();
+        }
+        state.cycle_found
+    }
+
     fn state_at(&self, num_rocks: usize) -> State {
         let mut state = State::new(num_rocks, self.jets.clone());
 
@@ -362,6 +432,23 @@ pub fn parse(input: &str) -> Result<Task> {
     input.parse::<Task>()
 }
 
+impl Solution for Task {
+    type Part1 = Int;
+    type Part2 = Int;
+
+    fn parse(input: &str) -> Result<Self> {
+        parse(input)
+    }
+
+    fn part1(&self) -> Result<Int> {
+        Ok(self.height_of_tower(2022))
+    }
+
+    fn part2(&self) -> Result<Int> {
+        Ok(self.height_of_tower(1_000_000_000_000))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -379,6 +466,13 @@ mod tests {
         assert_eq!(&task.jets[n - 5..n], &vec![R, L, L, R, R]);
     }
 
+    #[test]
+    fn bad_jets() {
+        assert!(parse("").is_err());
+        assert!(parse("\n").is_err());
+        assert!(parse("<x>").is_err());
+    }
+
     #[test]
     fn part1() {
         let task = EXAMPLE.parse::<Task>().unwrap();
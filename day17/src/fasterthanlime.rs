@@ -1,6 +1,7 @@
 // https://fasterthanli.me/series/advent-of-code-2022/part-17#part-2-rust
 // Added for benchmarking purposes.
 use color_eyre::{eyre::eyre, Report, Result};
+use common::Solution;
 use smallvec::{smallvec, SmallVec};
 
 #[derive(Eq, PartialEq)]
@@ -32,8 +33,10 @@ impl Task {
         // this is our key type
         #[derive(Clone, PartialEq, Eq, Hash)]
         struct StateKey {
-            // this could be a fixed-size array, but let's go easy for now
-            relative_heights: [usize; CHAMBER_WIDTH],
+            // the empty cells a falling rock could still get to, a bit per column and a row at a
+            // time down from the top.  the heights of the columns aren't enough, since rocks can be
+            // blown in under an overhang
+            surface: Vec<u8>,
             rock_index: usize,
             jet: usize,
         }
@@ -63,21 +66,17 @@ impl Task {
         // we've got a few more variables here
         let mut chamber = rustc_hash::FxHashSet::default();
         chamber.extend((0..7).map(|x| (x, 0)));
+        // the same cells again, a bit per column for each row, to find the surface quickly
+        let mut filled = vec![u8::MAX];
 
         let mut highest = 0;
         let mut jet: usize = 0;
-        // XXX: 💀
-        // the above is my original comment on this: this is extremely loosely
-        // typed.  the key are 7 y-coordinates, which correspond to the height of
-        // the rocks in the chamber, relative to the lowest one of them - see below
-        // for a diagram.
         let mut states = rustc_hash::FxHashMap::<StateKey, StateValue>::default();
         let mut total_rocks = 0;
         let mut rock_index: usize = 0;
         let mut cycle_found = false;
         let mut height_gain_in_cycle = 0;
         let mut skipped_cycles = 0;
-        let mut max_heights = [0usize; CHAMBER_WIDTH];
 
         // this is now a while loop, since we need to skip ahead using the cycles.
         while total_rocks < num_rocks {
@@ -132,11 +131,11 @@ impl Task {
                     for n in &rock {
                         if chamber.contains(&(n[0], n[1] - 1)) {
                             for m in &rock {
-                                // 👇
-                                if max_heights[m[0]] < m[1] {
-                                    max_heights[m[0]] = m[1];
-                                }
                                 chamber.insert((m[0], m[1]));
+                                if filled.len() <= m[1] {
+                                    filled.resize(m[1] + 1, 0);
+                                }
+                                filled[m[1]] |= 1 << m[0];
                             }
                             rest = true;
                             highest = rock
@@ -163,18 +162,32 @@ impl Task {
             // already found a cycle earlier (which means we already skipped ahead,
             // which means we can just jump forward)
             if !cycle_found {
-                let mut relative_heights = max_heights;
-                let lowest = relative_heights.iter().copied().min().unwrap();
-
-                for h in &mut relative_heights {
-                    *h -= lowest;
+                // work out which empty cells a rock could still get to, a row at a time from the
+                // top down, since rocks only move sideways and down
+                let mut surface = vec![];
+                let mut reached = u8::MAX;
+                for y in (1..=highest).rev() {
+                    let empty = !filled[y] & ((1 << CHAMBER_WIDTH) - 1);
+                    let mut next = reached & empty;
+                    loop {
+                        let wider = (next | next << 1 | next >> 1) & empty;
+                        if wider == next {
+                            break;
+                        }
+                        next = wider;
+                    }
+                    if next == 0 {
+                        break;
+                    }
+                    surface.push(next);
+                    reached = next;
                 }
 
                 let state_key = StateKey {
-                    relative_heights,
+                    surface,
                     rock_index,
                     jet,
-                }; // lowest is `2`
+                };
 
                 if let Some(state_value) = states.get(&state_key) {
                     // look mom! no more magic indexes! fields are named, and it
@@ -209,6 +222,23 @@ pub fn parse(input: &str) -> Result<Task> {
     })
 }
 
+impl Solution for Task {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        parse(input)
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(self.height_of_tower(2022))
+    }
+
+    fn part2(&self) -> Result<usize> {
+        Ok(self.height_of_tower(1_000_000_000_000))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,31 @@
+// Jet patterns `size` pushes long, and at least forty, pushing left a little more often than right
+// as the real ones tend to.  Part 2 drops so many rocks that it relies on the tower falling into a
+// cycle, which it doesn't when the jets keep the rocks out of a column.  Short patterns often do,
+// so a pattern is only kept once the tower has been seen to fall into a cycle.
+use crate::cycles::Task;
+use common::generate::{Rng, StdRng};
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(40);
+
+    loop {
+        let jets = (0..size)
+            .map(|_| if rng.gen_bool(0.55) { '<' } else { '>' })
+            .collect::<String>();
+        let task = jets.parse::<Task>().unwrap();
+        if task.settles_within(50 * size) {
+            return jets + "\n";
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<Task>(input, 40, 0..10).unwrap();
+        common::generate::check::<Task>(input, 10, 0..10).unwrap();
+    }
+}
@@ -0,0 +1,64 @@
+// The tower of the 2022 rocks of the first part, a pixel per cell with the top of the tower at the
+// top of the image.  Each rock is colored by its shape, so the pattern the jets make shows up as
+// bands.
+use crate::cycles::{Chamber, Rock, State, Task};
+use color_eyre::Result;
+use common::image::{Image, Rgb};
+use geometry::Vec2;
+
+const NUM_ROCKS: usize = 2022;
+
+const EMPTY: Rgb = [20, 20, 30];
+const SHAPES: [Rgb; State::NUM_SHAPES] = [
+    [220, 80, 70],
+    [240, 180, 60],
+    [90, 190, 90],
+    [70, 140, 220],
+    [170, 100, 200],
+];
+
+pub fn draw(input: &str) -> Result<Image> {
+    let task = input.parse::<Task>()?;
+    let mut chamber = Chamber::default();
+    let mut jets = task.jets.iter().cycle();
+    // The shape of the rock that settled in each cell, by row from the floor up
+    let mut shapes: Vec<[Option<usize>; 7]> = vec![];
+
+    for n in 0..NUM_ROCKS {
+        let shape = n % State::NUM_SHAPES;
+        let mut rock = Rock {
+            shape: State::SHAPES[shape],
+            bottom_left: Vec2::new(2, chamber.max_i + 4),
+        };
+        while rock.step(&chamber, *jets.next().unwrap()) {}
+
+        for p in rock.points() {
+            let y = p.y as usize;
+            if shapes.len() <= y {
+                shapes.resize(y + 1, [None; 7]);
+            }
+            shapes[y][p.x as usize] = Some(shape);
+        }
+        chamber.insert(rock);
+    }
+
+    let height = shapes.len();
+    Ok(Image::from_fn(7, height, |x, y| {
+        shapes[height - 1 - y][x].map_or(EMPTY, |shape| SHAPES[shape])
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let image = draw(include_str!("../data/example.txt")).unwrap();
+
+        assert_eq!((image.width(), image.height()), (7, 3068));
+        // The first rock lies flat on the floor
+        assert_eq!(image.get(2, 3067), Some(SHAPES[0]));
+        assert_eq!(image.get(0, 3067), Some(EMPTY));
+    }
+}
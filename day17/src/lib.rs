@@ -1,5 +1,36 @@
+pub mod animate;
 pub mod chamber;
 pub mod cycles;
 pub mod fasterthanlime;
+pub mod generate;
+pub mod image;
 pub mod naive;
 pub mod relative;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn jets() -> impl Strategy<Value = String> {
+        prop::collection::vec(prop::bool::ANY, 1..=50).prop_map(|jets| {
+            jets.iter()
+                .map(|&left| if left { '<' } else { '>' })
+                .collect()
+        })
+    }
+
+    // The five towers should stand just as high whatever the jets, and proptest shrinks the jets and
+    // the number of rocks down to a small case when they don't
+    proptest! {
+        #[test]
+        fn same_height(input in jets(), num_rocks in 0..=1000usize) {
+            let height = naive::parse(&input).unwrap().height_of_tower(num_rocks) as i64;
+
+            prop_assert_eq!(chamber::parse(&input).unwrap().height_of_tower(num_rocks) as i64, height);
+            prop_assert_eq!(relative::parse(&input).unwrap().height_of_tower(num_rocks) as i64, height);
+            prop_assert_eq!(fasterthanlime::parse(&input).unwrap().height_of_tower(num_rocks) as i64, height);
+            prop_assert_eq!(cycles::parse(&input).unwrap().height_of_tower(num_rocks), height);
+        }
+    }
+}
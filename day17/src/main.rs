@@ -3,12 +3,10 @@
 // - https://www.youtube.com/watch?v=QXTBseFzkW4 (Python)
 use color_eyre::{self, Result};
 use day17::cycles;
-use std::io::{self, Read};
 
 fn main() -> Result<()> {
     color_eyre::install()?;
-    let mut input = String::new();
-    io::stdin().read_to_string(&mut input)?;
+    let input = common::input::stdin()?;
 
     // The final and fastest solution that can complete both parts
     let task = cycles::parse(&input)?;
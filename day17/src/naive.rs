@@ -1,12 +1,17 @@
-use color_eyre::{self, Report, Result};
+use color_eyre::{self, eyre::eyre, Report, Result};
+use common::Solution;
+use geometry::Vec2;
+use grid::{Grid, Pos};
 use std::{
-    collections::VecDeque,
     fmt::{Debug, Write},
     str::FromStr,
 };
 
 const COLS: usize = 7;
 
+// x is the column and y the row of the tower, counting down from the top
+type Point = Vec2<i16>;
+
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 enum Direction {
     Left = -1,
@@ -23,6 +28,39 @@ enum Shape {
 }
 
 impl Shape {
+    const HORIZONTAL: [Point; 4] = [
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(2, 0),
+        Point::new(3, 0),
+    ];
+    const PLUS: [Point; 5] = [
+        Point::new(1, 0),
+        Point::new(0, 1),
+        Point::new(1, 1),
+        Point::new(2, 1),
+        Point::new(1, 2),
+    ];
+    const REVERSE_L: [Point; 5] = [
+        Point::new(2, 0),
+        Point::new(2, 1),
+        Point::new(0, 2),
+        Point::new(1, 2),
+        Point::new(2, 2),
+    ];
+    const VERTICAL: [Point; 4] = [
+        Point::new(0, 0),
+        Point::new(0, 1),
+        Point::new(0, 2),
+        Point::new(0, 3),
+    ];
+    const SQUARE: [Point; 4] = [
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(0, 1),
+        Point::new(1, 1),
+    ];
+
     fn height(&self) -> usize {
         match self {
             Self::Horizontal => 1,
@@ -32,78 +70,55 @@ impl Shape {
         }
     }
 
-    fn points(&self) -> &[(i16, i16)] {
+    fn points(&self) -> &[Point] {
         match self {
-            Self::Horizontal => &[(0, 0), (0, 1), (0, 2), (0, 3)],
-            Self::Plus => &[(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)],
-            Self::ReverseL => &[(0, 2), (1, 2), (2, 0), (2, 1), (2, 2)],
-            Self::Vertical => &[(0, 0), (1, 0), (2, 0), (3, 0)],
-            Self::Square => &[(0, 0), (0, 1), (1, 0), (1, 1)],
+            Self::Horizontal => &Self::HORIZONTAL,
+            Self::Plus => &Self::PLUS,
+            Self::ReverseL => &Self::REVERSE_L,
+            Self::Vertical => &Self::VERTICAL,
+            Self::Square => &Self::SQUARE,
         }
     }
 
-    fn shift_horizontal(
-        &self,
-        i: i16,
-        j: i16,
-        dj_delta: i16,
-    ) -> impl Iterator<Item = (i16, i16)> + '_ {
+    fn shift_horizontal(&self, p: Point, dj_delta: i16) -> impl Iterator<Item = Point> + '_ {
         self.points()
             .iter()
-            .map(move |(di, dj)| (i + di, j + dj + dj_delta))
+            .map(move |&d| p + d + Point::new(dj_delta, 0))
     }
 
-    fn shift_vertical(
-        &self,
-        i: i16,
-        j: i16,
-        di_delta: i16,
-    ) -> impl Iterator<Item = (i16, i16)> + '_ {
+    fn shift_vertical(&self, p: Point, di_delta: i16) -> impl Iterator<Item = Point> + '_ {
         self.points()
             .iter()
-            .map(move |(di, dj)| (i + di + di_delta, j + dj))
+            .map(move |&d| p + d + Point::new(0, di_delta))
     }
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq)]
 enum Cell {
     Empty,
     SettledRock,
 }
 
-pub struct Row([Cell; COLS]);
-
-impl Row {
-    const EMPTY: [Cell; COLS] = [Cell::Empty; COLS];
-
-    fn new() -> Self {
-        Self(Self::EMPTY)
-    }
-}
-
-impl Debug for Row {
+impl Debug for Cell {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for cell in self.0 {
-            let c = match cell {
-                Cell::Empty => '.',
-                Cell::SettledRock => '#',
-            };
-            f.write_char(c)?;
+        match self {
+            Cell::Empty => f.write_char('.'),
+            Cell::SettledRock => f.write_char('#'),
         }
-        Ok(())
     }
 }
 
 struct Tower {
-    rows: VecDeque<Row>,
+    // From the top down, with room above the highest rock for the next one to fall into
+    rows: Grid<Cell>,
     height: usize,
 }
 
 impl Debug for Tower {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str("\n")?;
-        for row in &self.rows {
-            writeln!(f, "|{:?}|", row)?;
+        for line in format!("{:?}", self.rows).lines() {
+            writeln!(f, "|{line}|")?;
         }
         f.write_str("+-------+\n")
     }
@@ -112,18 +127,13 @@ impl Debug for Tower {
 impl Tower {
     fn new() -> Self {
         Self {
-            rows: VecDeque::new(),
+            rows: Grid::new(COLS, 0, Cell::Empty),
             height: 0,
         }
     }
 
-    fn is_clear(&self, i: i16, j: i16) -> bool {
-        let cell = self
-            .rows
-            .get(i as usize)
-            .and_then(|row| row.0.get(j as usize));
-
-        cell == Some(&Cell::Empty)
+    fn is_clear(&self, p: Point) -> bool {
+        self.rows.get(Pos::new(p.y as usize, p.x as usize)) == Some(&Cell::Empty)
     }
 
     pub fn height(&self) -> usize {
@@ -131,51 +141,49 @@ impl Tower {
     }
 
     fn cap(&self) -> usize {
-        self.rows.len()
+        self.rows.height()
     }
 
     fn ensure_capacity(&mut self, height: usize) {
-        if self.rows.len() < (self.height() + height) {
-            for _ in 0..10 {
-                self.rows.push_front(Row::new())
-            }
+        if self.rows.height() < (self.height() + height) {
+            self.rows.insert_rows(0, 10, Cell::Empty);
         }
     }
 
-    fn set(&mut self, i: i16, j: i16, next: Cell) {
-        debug_assert!(i >= 0);
-        debug_assert!((0..7).contains(&j));
+    fn set(&mut self, p: Point, next: Cell) {
+        debug_assert!(p.y >= 0);
+        debug_assert!((0..7).contains(&p.x));
 
-        let cell = self.rows[i as usize].0.get_mut(j as usize).unwrap();
+        let cell = &mut self.rows[Pos::new(p.y as usize, p.x as usize)];
         debug_assert_eq!(*cell, Cell::Empty, "tried to overwrite an existing rock");
 
         *cell = next;
     }
 
     fn add(&mut self, rock: Rock) {
-        rock.points()
-            .for_each(|(i, j)| self.set(i, j, Cell::SettledRock));
-        self.height = self.height.max(self.rows.len() - rock.i as usize);
+        rock.points().for_each(|p| self.set(p, Cell::SettledRock));
+        self.height = self
+            .height
+            .max(self.rows.height() - rock.top_left.y as usize);
     }
 }
 
 #[derive(Clone, Debug)]
 pub struct Rock {
     shape: Shape,
-    i: i16,
-    j: i16,
+    top_left: Point,
 }
 
 impl Rock {
     fn step(&mut self, tower: &Tower, dj: Direction) -> bool {
         // Can we move laterally?
         if self.horizontal_clearance(tower, dj as i16) {
-            self.j += dj as i16;
+            self.top_left.x += dj as i16;
         }
 
         // Can we move down?
         if self.vertical_clearance(tower, 1) {
-            self.i += 1;
+            self.top_left.y += 1;
             return true;
         }
 
@@ -184,21 +192,18 @@ impl Rock {
 
     fn horizontal_clearance(&self, tower: &Tower, dj: i16) -> bool {
         self.shape
-            .shift_horizontal(self.i, self.j, dj)
-            .all(|(i, j)| tower.is_clear(i, j))
+            .shift_horizontal(self.top_left, dj)
+            .all(|p| tower.is_clear(p))
     }
 
     fn vertical_clearance(&self, tower: &Tower, di: i16) -> bool {
         self.shape
-            .shift_vertical(self.i, self.j, di)
-            .all(|(i, j)| tower.is_clear(i, j))
+            .shift_vertical(self.top_left, di)
+            .all(|p| tower.is_clear(p))
     }
 
-    fn points(&self) -> impl Iterator<Item = (i16, i16)> + '_ {
-        self.shape
-            .points()
-            .iter()
-            .map(|&(di, dj)| (self.i + di, self.j + dj))
+    fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.shape.points().iter().map(|&d| self.top_left + d)
     }
 }
 
@@ -214,11 +219,14 @@ impl FromStr for Task {
             .trim()
             .chars()
             .map(|c| match c {
-                '<' => Direction::Left,
-                '>' => Direction::Right,
-                _ => panic!("unexpected character"),
+                '<' => Ok(Direction::Left),
+                '>' => Ok(Direction::Right),
+                c => Err(eyre!("unexpected character in the jet pattern: {c:?}")),
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>>>()?;
+        if gusts.is_empty() {
+            return Err(eyre!("the jet pattern is empty"));
+        }
 
         Ok(Self { gusts })
     }
@@ -249,8 +257,7 @@ impl Task {
 
             let mut rock = Rock {
                 shape,
-                i: start,
-                j: 2,
+                top_left: Point::new(2, start),
             };
 
             loop {
@@ -280,6 +287,23 @@ pub fn parse(input: &str) -> Result<Task> {
     input.parse::<Task>()
 }
 
+impl Solution for Task {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        parse(input)
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(self.height_of_tower(2022))
+    }
+
+    fn part2(&self) -> Result<usize> {
+        Err(eyre!("this implementation is too slow to drop 1e12 rocks"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
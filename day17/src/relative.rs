@@ -1,11 +1,15 @@
-use color_eyre::{self, Report, Result};
+use color_eyre::{self, eyre::eyre, Report, Result};
+use common::Solution;
+use geometry::Vec2;
+use grid::{Grid, Pos};
 use std::{
     fmt::{Debug, Write},
     str::FromStr,
 };
 
 type Int = i16;
-type Point = (Int, Int);
+// x is the column and y the height above the floor
+type Point = Vec2<Int>;
 
 const COLS: usize = 7;
 
@@ -25,60 +29,80 @@ enum Shape {
 }
 
 impl Shape {
+    const HORIZONTAL: [Point; 4] = [
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(2, 0),
+        Point::new(3, 0),
+    ];
+    const PLUS: [Point; 5] = [
+        Point::new(1, 0),
+        Point::new(0, 1),
+        Point::new(1, 1),
+        Point::new(2, 1),
+        Point::new(1, 2),
+    ];
+    const REVERSE_L: [Point; 5] = [
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(2, 0),
+        Point::new(2, 1),
+        Point::new(2, 2),
+    ];
+    const VERTICAL: [Point; 4] = [
+        Point::new(0, 0),
+        Point::new(0, 1),
+        Point::new(0, 2),
+        Point::new(0, 3),
+    ];
+    const SQUARE: [Point; 4] = [
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(0, 1),
+        Point::new(1, 1),
+    ];
+
     fn points(&self) -> &[Point] {
         match self {
-            Self::Horizontal => &[(0, 0), (0, 1), (0, 2), (0, 3)],
-            Self::Plus => &[(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)],
-            Self::ReverseL => &[(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)],
-            Self::Vertical => &[(0, 0), (1, 0), (2, 0), (3, 0)],
-            Self::Square => &[(0, 0), (0, 1), (1, 0), (1, 1)],
+            Self::Horizontal => &Self::HORIZONTAL,
+            Self::Plus => &Self::PLUS,
+            Self::ReverseL => &Self::REVERSE_L,
+            Self::Vertical => &Self::VERTICAL,
+            Self::Square => &Self::SQUARE,
         }
     }
 
     fn shift_horizontal(&self, p: Point, dj_delta: Int) -> impl Iterator<Item = Point> + '_ {
         self.points()
             .iter()
-            .map(move |(di, dj)| (p.0 + di, p.1 + dj + dj_delta))
+            .map(move |&d| p + d + Point::new(dj_delta, 0))
     }
 
     fn shift_vertical(&self, p: Point, di_delta: Int) -> impl Iterator<Item = Point> + '_ {
         self.points()
             .iter()
-            .map(move |(di, dj)| (p.0 + di + di_delta, p.1 + dj))
+            .map(move |&d| p + d + Point::new(0, di_delta))
     }
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq)]
 enum Cell {
     Empty,
     SettledRock,
 }
 
-pub struct Row([Cell; COLS]);
-
-impl Row {
-    const EMPTY: [Cell; COLS] = [Cell::Empty; COLS];
-
-    fn new() -> Self {
-        Self(Self::EMPTY)
-    }
-}
-
-impl Debug for Row {
+impl Debug for Cell {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for cell in self.0 {
-            let c = match cell {
-                Cell::Empty => '.',
-                Cell::SettledRock => '#',
-            };
-            f.write_char(c)?;
+        match self {
+            Cell::Empty => f.write_char('.'),
+            Cell::SettledRock => f.write_char('#'),
         }
-        Ok(())
     }
 }
 
 struct Chamber {
-    pub rows: Vec<Row>,
+    // Row i of the grid is i above the floor, so the chamber is upside down in it
+    rows: Grid<Cell>,
     pub max_i_by_col: [Int; COLS],
     pub max_i: Int,
 }
@@ -86,24 +110,32 @@ struct Chamber {
 impl Debug for Chamber {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str("\n|-------|\n")?;
-        for row in self.rows.iter().rev() {
-            writeln!(f, "|{:?}|", row)?;
+        if let Ok(grid) = self.grid() {
+            for line in format!("{grid:?}").lines() {
+                writeln!(f, "|{line}|")?;
+            }
         }
         f.write_str("+-------+\n")
     }
 }
 
 impl Chamber {
+    // The rows of the chamber from the top down
+    fn grid(&self) -> Result<Grid<Cell>> {
+        let height = self.rows.height();
+        Grid::from_rows((0..height).rev().map(|i| self.rows.row(i).to_vec()))
+    }
+
     fn new() -> Self {
         Self {
-            rows: Vec::with_capacity(4096),
+            rows: Grid::new(COLS, 0, Cell::Empty),
             max_i_by_col: [-1; COLS],
             max_i: -1,
         }
     }
 
     fn is_available(&self, p: Point) -> bool {
-        let (i, j) = p;
+        let Vec2 { x: j, y: i } = p;
 
         if j < 0 {
             return false;
@@ -121,11 +153,11 @@ impl Chamber {
 
         // If i goes beyond the current capacity of the chamber, there are no obstructions, and the
         // block can be placed here, assuming additional capacity is added.
-        if i >= self.rows.len() {
+        if i >= self.rows.height() {
             return true;
         }
 
-        self.rows[i].0.get(j) == Some(&Cell::Empty)
+        self.rows[Pos::new(i, j)] == Cell::Empty
     }
 
     pub fn height(&self) -> Int {
@@ -133,21 +165,20 @@ impl Chamber {
     }
 
     fn set(&mut self, p: Point, next: Cell) {
-        debug_assert!(p.0 >= 0);
-        let i = p.0 as usize;
+        debug_assert!(p.y >= 0);
+        let i = p.y as usize;
 
-        debug_assert!((0..7).contains(&p.1));
+        debug_assert!((0..7).contains(&p.x));
 
-        if i >= self.rows.len() {
-            for _ in 0..10 {
-                self.rows.push(Row::new());
-            }
+        let height = self.rows.height();
+        if i >= height {
+            self.rows.insert_rows(height, 10, Cell::Empty);
         }
 
-        let cell = self.rows[i]
-            .0
-            .get_mut(p.1 as usize)
-            .expect("p.1 within column bounds");
+        let cell = self
+            .rows
+            .get_mut(Pos::new(i, p.x as usize))
+            .expect("p.x within column bounds");
 
         debug_assert_eq!(*cell, Cell::Empty, "tried to overwrite an existing rock");
 
@@ -158,8 +189,8 @@ impl Chamber {
         let mut max_i = -1;
 
         for p in rock.points() {
-            let j = p.1 as usize;
-            self.max_i_by_col[j] = self.max_i_by_col[j].max(p.0);
+            let j = p.x as usize;
+            self.max_i_by_col[j] = self.max_i_by_col[j].max(p.y);
             max_i = max_i.max(self.max_i_by_col[j]);
             self.set(p, Cell::SettledRock);
         }
@@ -178,12 +209,12 @@ impl Rock {
     fn step(&mut self, chamber: &Chamber, dj: Direction) -> bool {
         // Can we move laterally?
         if self.horizontal_clearance(chamber, dj as Int) {
-            self.bottom_left.1 += dj as Int;
+            self.bottom_left.x += dj as Int;
         }
 
         // Can we move down?
         if self.vertical_clearance(chamber, -1) {
-            self.bottom_left.0 -= 1;
+            self.bottom_left.y -= 1;
             return true;
         }
 
@@ -204,10 +235,7 @@ impl Rock {
 
     fn points(&self) -> impl Iterator<Item = Point> + '_ {
         let p = self.bottom_left;
-        self.shape
-            .points()
-            .iter()
-            .map(move |&(di, dj)| (p.0 + di, p.1 + dj))
+        self.shape.points().iter().map(move |&d| p + d)
     }
 }
 
@@ -223,11 +251,14 @@ impl FromStr for Task {
             .trim()
             .chars()
             .map(|c| match c {
-                '<' => Direction::Left,
-                '>' => Direction::Right,
-                _ => panic!("unexpected character"),
+                '<' => Ok(Direction::Left),
+                '>' => Ok(Direction::Right),
+                c => Err(eyre!("unexpected character in the jet pattern: {c:?}")),
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>>>()?;
+        if gusts.is_empty() {
+            return Err(eyre!("the jet pattern is empty"));
+        }
 
         Ok(Self { gusts })
     }
@@ -258,7 +289,7 @@ impl Task {
         for r in 0..num_rocks {
             let mut rock = Rock {
                 shape: Self::SHAPES[r % Self::NUM_SHAPES],
-                bottom_left: (chamber.max_i + 4, 2),
+                bottom_left: Point::new(2, chamber.max_i + 4),
             };
 
             while rock.step(&chamber, self.gusts[step % n]) {
@@ -278,6 +309,23 @@ pub fn parse(input: &str) -> Result<Task> {
     input.parse::<Task>()
 }
 
+impl Solution for Task {
+    type Part1 = Int;
+    type Part2 = Int;
+
+    fn parse(input: &str) -> Result<Self> {
+        parse(input)
+    }
+
+    fn part1(&self) -> Result<Int> {
+        Ok(self.height_of_tower(2022))
+    }
+
+    fn part2(&self) -> Result<Int> {
+        Err(eyre!("this implementation is too slow to drop 1e12 rocks"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
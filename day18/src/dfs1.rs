@@ -1,6 +1,8 @@
 // From https://github.com/noah-clements/AoC2022/blob/master/day18/day18.py
-use crate::{Cube, Input, Int};
+use crate::{adjacent, Cube, Input, Int};
 use color_eyre::Result;
+use common::Solution;
+use geometry::Bounds;
 use std::collections::HashSet;
 
 pub struct Task {
@@ -16,7 +18,7 @@ impl Task {
         for i in 0..n {
             let a = cubes[i];
             for b in cubes.iter().take(n).skip(i + 1) {
-                ans -= 2 * a.adjacent(b) as Int;
+                ans -= 2 * adjacent(&a, b) as Int;
             }
         }
 
@@ -26,43 +28,16 @@ impl Task {
     pub fn exposed_area(&self) -> Int {
         let cubes = self.input.0.clone();
 
-        let (min_x, max_x) = (
-            cubes.iter().map(|c| c.x).min().unwrap_or(Int::MAX) - 1,
-            cubes.iter().map(|c| c.x).max().unwrap_or(Int::MIN) + 2,
-        );
-        let (min_y, max_y) = (
-            cubes.iter().map(|c| c.y).min().unwrap_or(Int::MAX) - 1,
-            cubes.iter().map(|c| c.y).max().unwrap_or(Int::MIN) + 2,
-        );
-        let (min_z, max_z) = (
-            cubes.iter().map(|c| c.z).min().unwrap_or(Int::MAX) - 1,
-            cubes.iter().map(|c| c.z).max().unwrap_or(Int::MIN) + 2,
-        );
-
-        let in_bounds = |&Cube { x, y, z }: &Cube| -> bool {
-            let good_x = min_x <= x && x <= max_x;
-            let good_y = min_y <= y && y <= max_y;
-            let good_z = min_z <= z && z <= max_z;
-            good_x && good_y && good_z
+        // Leave room for the water to flow all the way around the droplet
+        let Some(bounds) = Bounds::from_points(cubes.iter().copied()) else {
+            return 0;
         };
+        let bounds = bounds.expand(Cube::splat(1));
 
-        let mut water = vec![Cube {
-            x: min_x,
-            y: min_y,
-            z: min_z,
-        }];
+        let mut water = vec![bounds.min];
         let mut visited = HashSet::<Cube>::with_capacity(cubes.len());
         let mut water_sides = 0;
 
-        let deltas = [
-            (1, 0, 0),
-            (-1, 0, 0),
-            (0, 1, 0),
-            (0, -1, 0),
-            (0, 0, 1),
-            (0, 0, -1),
-        ];
-
         while !water.is_empty() {
             let cube = water.pop().unwrap();
             if visited.contains(&cube) {
@@ -70,9 +45,8 @@ impl Task {
             }
             visited.insert(cube);
 
-            for &dxyz in deltas.iter() {
-                let adjacent = cube.shift(dxyz);
-                if in_bounds(&adjacent) {
+            for adjacent in cube.neighbors6() {
+                if bounds.contains(adjacent) {
                     if cubes.contains(&adjacent) {
                         water_sides += 1;
                     } else {
@@ -91,6 +65,23 @@ pub fn parse(s: &str) -> Result<Task> {
     Ok(Task { input })
 }
 
+impl Solution for Task {
+    type Part1 = Int;
+    type Part2 = Int;
+
+    fn parse(input: &str) -> Result<Self> {
+        parse(input)
+    }
+
+    fn part1(&self) -> Result<Int> {
+        Ok(self.surface_area())
+    }
+
+    fn part2(&self) -> Result<Int> {
+        Ok(self.exposed_area())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,31 @@
+// Scans of `size` cubes, up to five thousand of them, so that the area still fits in the integers
+// it is counted with.  The cubes fill about half of a box, which leaves plenty of pockets of air
+// inside the droplet.
+use common::generate::{SliceRandom, StdRng};
+use itertools::Itertools;
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let count = size.min(5000);
+    let side = ((2 * count) as f64).cbrt().ceil() as i16;
+
+    let mut cubes = (0..side)
+        .cartesian_product(0..side)
+        .cartesian_product(0..side)
+        .collect_vec();
+    cubes.shuffle(rng);
+
+    cubes[..count]
+        .iter()
+        .map(|((x, y), z)| format!("{x},{y},{z}\n"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<crate::dfs1::Task>(input, 200, 0..10).unwrap();
+    }
+}
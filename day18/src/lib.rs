@@ -1,55 +1,29 @@
-use color_eyre::{self, Report, Result};
+use color_eyre::{self, eyre::eyre, Report, Result};
+use geometry::Vec3;
 use std::str::FromStr;
 
 pub mod dfs1;
 pub mod dfs2;
+pub mod generate;
 pub mod naive;
 
-#[derive(Clone, Copy, Debug)]
-enum Axis {
-    X = 0,
-    Y = 1,
-    Z = 2,
-}
-
 type Int = i16;
+type Cube = Vec3<Int>;
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
-struct Cube {
-    x: Int,
-    y: Int,
-    z: Int,
-}
+fn parse_cube(s: &str) -> Result<Cube> {
+    let a = s
+        .split(',')
+        .map(|v| v.trim().parse::<Int>())
+        .collect::<Result<Vec<Int>, _>>()?;
 
-impl FromStr for Cube {
-    type Err = Report;
-
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        let a = s
-            .split(',')
-            .map(|v| v.trim().parse::<Int>().expect("an integer"))
-            .collect::<Vec<Int>>();
-
-        Ok(Self {
-            x: a[0],
-            y: a[1],
-            z: a[2],
-        })
+    match a[..] {
+        [x, y, z] => Ok(Cube::new(x, y, z)),
+        _ => Err(eyre!("expected three coordinates: {s}")),
     }
 }
 
-impl Cube {
-    fn adjacent(&self, other: &Self) -> bool {
-        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z) == 1
-    }
-
-    fn shift(&self, (dx, dy, dz): (Int, Int, Int)) -> Self {
-        Self {
-            x: self.x + dx,
-            y: self.y + dy,
-            z: self.z + dz,
-        }
-    }
+fn adjacent(a: &Cube, b: &Cube) -> bool {
+    a.manhattan(*b) == 1
 }
 
 #[derive(Debug)]
@@ -62,7 +36,7 @@ impl FromStr for Input {
         let vec = value
             .trim()
             .lines()
-            .map(|l| l.trim().parse::<Cube>())
+            .map(|l| parse_cube(l.trim()))
             .collect::<Result<Vec<Cube>>>()?;
 
         Ok(Self(vec))
@@ -72,6 +46,18 @@ impl FromStr for Input {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
+
+    fn cubes() -> impl Strategy<Value = String> {
+        prop::collection::hash_set((0..8 as Int, 0..8 as Int, 0..8 as Int), 1..80).prop_map(
+            |cubes| {
+                cubes
+                    .iter()
+                    .map(|(x, y, z)| format!("{x},{y},{z}\n"))
+                    .collect()
+            },
+        )
+    }
 
     #[test]
     fn all_good() {
@@ -88,4 +74,22 @@ mod tests {
 
         check!(dfs1);
     }
+
+    // The three ways of working out the areas should agree on any droplet, and proptest shrinks the
+    // cubes down to a small droplet when they don't
+    proptest! {
+        #[test]
+        fn same_area(input in cubes()) {
+            let (naive, dfs1, dfs2) = (
+                naive::parse(&input).unwrap(),
+                dfs1::parse(&input).unwrap(),
+                dfs2::parse(&input).unwrap(),
+            );
+
+            prop_assert_eq!(dfs1.surface_area(), naive.surface_area());
+            prop_assert_eq!(dfs2.surface_area(), naive.surface_area());
+            prop_assert_eq!(dfs1.exposed_area(), naive.exposed_area());
+            prop_assert_eq!(dfs2.exposed_area(), naive.exposed_area());
+        }
+    }
 }
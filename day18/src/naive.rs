@@ -1,18 +1,18 @@
-use crate::{Axis, Cube, Input, Int};
+use crate::{Cube, Input, Int};
 use color_eyre::Result;
-use itertools::Itertools;
-use std::collections::HashMap;
+use common::Solution;
+use geometry::{Axis, Bounds};
+use itertools::{iproduct, Itertools};
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Hash, Eq, PartialEq)]
 struct Key(Int, Int);
 
-impl Cube {
-    fn key(&self, dim: Axis) -> Key {
-        match dim {
-            Axis::X => Key(self.y, self.z),
-            Axis::Y => Key(self.x, self.z),
-            Axis::Z => Key(self.x, self.y),
-        }
+fn key(p: &Cube, dim: Axis) -> Key {
+    match dim {
+        Axis::X => Key(p.y, p.z),
+        Axis::Y => Key(p.x, p.z),
+        Axis::Z => Key(p.x, p.y),
     }
 }
 
@@ -28,15 +28,7 @@ impl Column {
     }
 
     fn values(&self) -> impl Iterator<Item = Int> + '_ {
-        let value = |p: &Cube| -> Int {
-            match self.axis {
-                Axis::X => p.x,
-                Axis::Y => p.y,
-                Axis::Z => p.z,
-            }
-        };
-
-        self.points.iter().map(value)
+        self.points.iter().map(|p| p.get(self.axis))
     }
 }
 
@@ -60,7 +52,7 @@ impl PartialArea {
     }
 
     fn add(&mut self, p: Cube) {
-        let key = p.key(self.axis);
+        let key = key(&p, self.axis);
 
         self.points
             .entry(key)
@@ -113,21 +105,51 @@ pub struct Task {
 
 impl Task {
     pub fn surface_area(&self) -> Int {
-        self.state().surface_rea()
+        Self::state(self.input.0.iter().copied()).surface_rea()
     }
 
+    // The pockets of air inside the droplet can't be seen from outside, so fill them in and take
+    // the area of what's left.  Air is outside if it's at the edge of the box around the droplet or
+    // next to air that is outside, and the box is gone over again until no more is found.
     pub fn exposed_area(&self) -> Int {
-        58
+        let cubes = self.input.0.iter().copied().collect::<HashSet<_>>();
+        let Some(bounds) = Bounds::from_points(cubes.iter().copied()) else {
+            return 0;
+        };
+        let (min, max) = (bounds.min, bounds.max);
+
+        let air = iproduct!(min.x..=max.x, min.y..=max.y, min.z..=max.z)
+            .map(|(x, y, z)| Cube::new(x, y, z))
+            .filter(|p| !cubes.contains(p))
+            .collect_vec();
+
+        let mut outside = HashSet::new();
+        loop {
+            let found = outside.len();
+            for &p in &air {
+                if p.neighbors6()
+                    .any(|n| !bounds.contains(n) || outside.contains(&n))
+                {
+                    outside.insert(p);
+                }
+            }
+            if outside.len() == found {
+                break;
+            }
+        }
+
+        let filled = air.into_iter().filter(|p| !outside.contains(p));
+        Self::state(filled.chain(cubes)).surface_rea()
     }
 
-    fn state(&self) -> State {
+    fn state(points: impl IntoIterator<Item = Cube>) -> State {
         let mut areas = [
             PartialArea::new(Axis::X),
             PartialArea::new(Axis::Y),
             PartialArea::new(Axis::Z),
         ];
 
-        for &p in &self.input.0 {
+        for p in points {
             for area in &mut areas {
                 area.add(p);
             }
@@ -142,6 +164,23 @@ pub fn parse(s: &str) -> Result<Task> {
     Ok(Task { input })
 }
 
+impl Solution for Task {
+    type Part1 = Int;
+    type Part2 = Int;
+
+    fn parse(input: &str) -> Result<Self> {
+        parse(input)
+    }
+
+    fn part1(&self) -> Result<Int> {
+        Ok(self.surface_area())
+    }
+
+    fn part2(&self) -> Result<Int> {
+        Ok(self.exposed_area())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,5 +217,6 @@ mod tests {
         let input = include_str!("../data/input.txt");
         let task = parse(input).unwrap();
         assert_eq!(task.surface_area(), 4636);
+        assert_eq!(task.exposed_area(), 2572);
     }
 }
@@ -0,0 +1,5 @@
+common::benchmarks!(
+    "day19",
+    include_str!("../data/input.txt"),
+    day19::branch1::Task
+);
@@ -1,6 +1,9 @@
 // https://github.com/Crazytieguy/advent-of-code/blob/master/2022/src/bin/day19/main.rs
 use crate::{Blueprint, Input, Int, Resources, ONE_CLAY, ONE_OBSIDIAN, ONE_ORE};
 use color_eyre::Result;
+use common::Solution;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 #[derive(Clone, Copy, Default, Debug)]
 struct State {
@@ -116,8 +119,12 @@ pub struct Task {
 
 impl Task {
     pub fn total_quality_level(&self) -> Int {
-        self.blueprints()
-            .iter()
+        #[cfg(feature = "parallel")]
+        let blueprints = self.blueprints().par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let blueprints = self.blueprints().iter();
+
+        blueprints
             .map(|blueprint| {
                 let mut ans = 0;
                 branch_and_bound(blueprint, State::new(24), &mut ans);
@@ -148,6 +155,23 @@ pub fn parse(input: &str) -> Result<Task> {
     Ok(Task { input })
 }
 
+impl Solution for Task {
+    type Part1 = Int;
+    type Part2 = Int;
+
+    fn parse(input: &str) -> Result<Self> {
+        parse(input)
+    }
+
+    fn part1(&self) -> Result<Int> {
+        Ok(self.total_quality_level())
+    }
+
+    fn part2(&self) -> Result<Int> {
+        Ok(self.first_three())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
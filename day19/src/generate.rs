@@ -0,0 +1,45 @@
+// `size` blueprints, with costs in the ranges that the puzzle's own blueprints have.  The geodes
+// cracked by the first three blueprints are multiplied together in a u16, so those three are made
+// from the dearer end of the ranges, where fewer geodes get cracked.
+use common::generate::{Rng, StdRng};
+use std::ops::RangeInclusive;
+
+fn blueprint(
+    rng: &mut StdRng,
+    id: usize,
+    clay: RangeInclusive<u8>,
+    obsidian: RangeInclusive<u8>,
+) -> String {
+    format!(
+        "Blueprint {id}: \
+         Each ore robot costs {} ore. \
+         Each clay robot costs {} ore. \
+         Each obsidian robot costs {} ore and {} clay. \
+         Each geode robot costs {} ore and {} obsidian.\n",
+        rng.gen_range(2..=4),
+        rng.gen_range(2..=4),
+        rng.gen_range(2..=4),
+        rng.gen_range(clay),
+        rng.gen_range(2..=4),
+        rng.gen_range(obsidian),
+    )
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    (1..=size)
+        .map(|id| match id {
+            1..=3 => blueprint(rng, id, 12..=20, 12..=20),
+            _ => blueprint(rng, id, 5..=20, 7..=20),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<crate::branch1::Task>(input, 4, 0..10).unwrap();
+    }
+}
@@ -1,11 +1,11 @@
-use color_eyre::{eyre::eyre, Report};
+use color_eyre::Report;
+use common::parse::{context, finish, IResult};
 use nom::{
     bytes::complete::tag,
     character::complete::{multispace0, multispace1},
-    combinator::{all_consuming, map},
+    combinator::{all_consuming, cut, map},
     multi::fold_many1,
     sequence::tuple,
-    Finish, IResult,
 };
 use std::{
     ops::{Add, Mul},
@@ -13,6 +13,7 @@ use std::{
 };
 
 pub mod branch1;
+pub mod generate;
 
 pub type Int = u16;
 
@@ -202,8 +203,16 @@ fn parse_geode(i: &str) -> IResult<&str, Resources> {
 
 fn parse_blueprint(i: &str) -> IResult<&str, Blueprint> {
     map(
-        tuple((parse_id, parse_ore, parse_clay, parse_obsidian, parse_geode)),
-        |(id, ore, clay, obsidian, geode)| Blueprint {
+        tuple((
+            parse_id,
+            cut(tuple((
+                context("the cost of an ore robot", parse_ore),
+                context("the cost of a clay robot", parse_clay),
+                context("the cost of an obsidian robot", parse_obsidian),
+                context("the cost of a geode robot", parse_geode),
+            ))),
+        )),
+        |(id, (ore, clay, obsidian, geode))| Blueprint {
             id,
             ore_robot: ore,
             clay_robot: clay,
@@ -220,19 +229,16 @@ impl FromStr for Input {
     type Err = Report;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (remainder, blueprints) = all_consuming(fold_many1(
+        let blueprints = all_consuming(fold_many1(
             parse_blueprint,
             Vec::new,
             |mut acc, blueprint| {
                 acc.push(blueprint);
                 acc
             },
-        ))(s)
-        .finish()
-        .or(Err(eyre!("failed to parse input")))?;
-        assert!(remainder.trim().is_empty());
+        ))(s);
 
-        Ok(Input(blueprints))
+        Ok(Input(finish(s, blueprints)?))
     }
 }
 
@@ -263,4 +269,14 @@ mod tests {
         assert_eq!(b2.geode_robot.ore, 3);
         assert_eq!(b2.geode_robot.obsidian, 12);
     }
+
+    #[test]
+    fn parse_errors() {
+        let input = EXAMPLE.replacen("14 clay", "14 obsidian", 1);
+        let err = input.parse::<Input>().unwrap_err();
+        let err = err.downcast_ref::<common::parse::ParseError>().unwrap();
+
+        assert_eq!((err.line, err.column), (1, 109));
+        assert_eq!(err.expected, "the cost of an obsidian robot");
+    }
 }
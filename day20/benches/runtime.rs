@@ -0,0 +1,5 @@
+common::benchmarks!(
+    "day20",
+    include_str!("../data/input.txt"),
+    day20::naive::Task
+);
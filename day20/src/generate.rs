@@ -0,0 +1,32 @@
+// Encrypted files of `size` numbers, at least two of them, of the size the puzzle's numbers are.
+// Numbers other than zero can repeat, but there is exactly one zero to count the grove coordinates
+// from.
+use common::generate::{Rng, StdRng};
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(2);
+    let zero = rng.gen_range(0..size);
+
+    (0..size)
+        .map(|i| match i == zero {
+            true => 0,
+            false => loop {
+                let n = rng.gen_range(-10_000..=10_000);
+                if n != 0 {
+                    break n;
+                }
+            },
+        })
+        .map(|n| format!("{n}\n"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<crate::naive::Task>(input, 100, 0..10).unwrap();
+    }
+}
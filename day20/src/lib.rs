@@ -0,0 +1,8 @@
+// Reference solutions:
+// - https://github.com/Crazytieguy/advent-of-code/blob/master/2022/src/bin/day20/main.rs
+//   doubly-linked list
+// - https://github.com/schubart/AdventOfCode_2022_Rust/blob/master/day20/src/lib.rs
+//   O(n^2) solution, finding the position of the element and removing and inserting it.
+
+pub mod generate;
+pub mod naive;
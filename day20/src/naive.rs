@@ -1,5 +1,6 @@
 // Following https://github.com/schubart/AdventOfCode_2022_Rust/blob/master/day20/src/lib.rs
 use color_eyre::{eyre::eyre, Report, Result};
+use common::Solution;
 use std::str::FromStr;
 
 type Int = i64;
@@ -21,6 +22,20 @@ impl FromStr for Input {
             .collect::<Result<Vec<Int>>>()
             .or(Err(eyre!("failed to parse input")))?;
 
+        // Mixing moves numbers around a circle of all the others, and decoding counts from 0
+        if input.len() < 2 {
+            return Err(eyre!("there have to be at least two numbers to mix"));
+        }
+        if !input.contains(&0) {
+            return Err(eyre!("there's no 0 to count from"));
+        }
+        if let Some(v) = input
+            .iter()
+            .find(|v| v.checked_mul(Task::DECRIPTION_KEY).is_none())
+        {
+            return Err(eyre!("{v} is too big to decrypt"));
+        }
+
         Ok(Self(input))
     }
 }
@@ -44,7 +59,8 @@ impl Task {
 
     fn decode(&self, values: &Vec<Int>) -> Int {
         let n = values.len();
-        let i_zero = values.iter().position(|&v| v == 0).expect("zero value");
+        // There's always a 0, as parsing checks for one
+        let i_zero = values.iter().position(|&v| v == 0).unwrap();
         values[(1000 + i_zero) % n] + values[(2000 + i_zero) % n] + values[(3000 + i_zero) % n]
     }
 
@@ -62,7 +78,6 @@ impl Task {
         // Q: Why are we using n-1? A: According to the link at the top of the file, it's due to
         // the problem statement: moving an element by (n - 1) places in a list of length n leaves
         // list unchanged.
-        assert!(!numbers.is_empty());
         let n = numbers.len();
 
         for _ in 0..rounds {
@@ -94,6 +109,23 @@ pub fn parse(s: &str) -> Result<Task> {
     Ok(Task { input })
 }
 
+impl Solution for Task {
+    type Part1 = Int;
+    type Part2 = Int;
+
+    fn parse(input: &str) -> Result<Self> {
+        parse(input)
+    }
+
+    fn part1(&self) -> Result<Int> {
+        Ok(self.part1())
+    }
+
+    fn part2(&self) -> Result<Int> {
+        Ok(self.part2())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,6 +145,20 @@ mod tests {
         assert_eq!(task.part1(), 3);
     }
 
+    #[test]
+    fn bad_input() {
+        assert!(parse("").is_err());
+        assert!(parse("0").is_err());
+        assert!(parse("1\n1").is_err());
+        assert!(parse("0\n9223372036854775807").is_err());
+        assert!(parse("0\nx").is_err());
+
+        // The smallest circle there is still mixes and decodes
+        let task = parse("0\n5").unwrap();
+        task.part1();
+        task.part2();
+    }
+
     #[test]
     fn part2() {
         let task = parse(EXAMPLE).unwrap();
@@ -0,0 +1,5 @@
+common::benchmarks!(
+    "day21",
+    include_str!("../data/input.txt"),
+    day21::solve::Task
+);
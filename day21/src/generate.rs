@@ -0,0 +1,156 @@
+// Riddles with `size` monkeys between root and humn.  The riddle is built from the answer to the
+// second part up: humn's number goes through an operation with a known number at each monkey on
+// the way to root, chosen so that it can be undone exactly, and the other side of root is made to
+// come to the same number.  The known numbers come from small trees of monkeys that work them out
+// exactly too.
+//
+// Multiplications are held back so that the numbers stay well inside an i64, whatever humn shouts
+// in the first part.
+use crate::Int;
+use common::generate::{Rng, SliceRandom, StdRng};
+use std::collections::HashSet;
+
+struct Riddle<'r> {
+    rng: &'r mut StdRng,
+    names: HashSet<String>,
+    jobs: Vec<String>,
+}
+
+impl<'r> Riddle<'r> {
+    fn name(&mut self) -> String {
+        loop {
+            let name = (0..4)
+                .map(|_| char::from(self.rng.gen_range(b'a'..=b'z')))
+                .collect::<String>();
+            if name != "root" && name != "humn" && self.names.insert(name.clone()) {
+                break name;
+            }
+        }
+    }
+
+    fn job(&mut self, name: &str, job: String) {
+        self.jobs.push(format!("{name}: {job}\n"));
+    }
+
+    // A monkey, and the monkeys it waits for, that comes to the given number
+    fn known(&mut self, value: Int, depth: usize) -> String {
+        let name = self.name();
+
+        if value > 0 && (depth == 0 || self.rng.gen_bool(0.3)) {
+            self.job(&name, value.to_string());
+            return name;
+        }
+
+        let depth = depth.saturating_sub(1);
+        let divisor = (2..=9).filter(|k| value != 0 && value % k == 0).last();
+        let (lhs, op, rhs) = match self.rng.gen_range(0..4) {
+            0 if value > 1 => {
+                let a = self.rng.gen_range(1..value);
+                (self.known(a, depth), '+', self.known(value - a, depth))
+            }
+            1 if divisor.is_some() => {
+                let k = divisor.unwrap();
+                (self.known(value / k, depth), '*', self.known(k, depth))
+            }
+            2 if value.abs() < 1_000_000 => {
+                let k = self.rng.gen_range(2..=9);
+                (self.known(value * k, depth), '/', self.known(k, depth))
+            }
+            _ => {
+                let b = self.rng.gen_range(1..=100);
+                (self.known(value + b, depth), '-', self.known(b, depth))
+            }
+        };
+
+        self.job(&name, format!("{lhs} {op} {rhs}"));
+        name
+    }
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let answer: Int = rng.gen_range(1..100_000);
+    let shout = rng.gen_range(1..=1000);
+
+    let mut riddle = Riddle {
+        rng,
+        names: HashSet::new(),
+        jobs: vec![format!("humn: {shout}\n")],
+    };
+
+    // How much a change in humn's number changes the number going up the path
+    let mut slope = 1.0;
+    let (mut unknown, mut value) = ("humn".to_owned(), answer);
+
+    for _ in 0..size.max(1) {
+        let name = riddle.name();
+        let k = riddle.rng.gen_range(2..=9);
+        let divides = value % k == 0;
+
+        let (job, next) = match riddle.rng.gen_range(0..5) {
+            0 if slope * (k as f64) < 1e4 && value.abs() < 10_000_000 => {
+                slope *= k as f64;
+                let known = riddle.known(k, 1);
+                match riddle.rng.gen_bool(0.5) {
+                    true => (format!("{unknown} * {known}"), value * k),
+                    false => (format!("{known} * {unknown}"), value * k),
+                }
+            }
+            1 if divides => {
+                slope /= k as f64;
+                let known = riddle.known(k, 1);
+                (format!("{unknown} / {known}"), value / k)
+            }
+            2 => {
+                let c = riddle.rng.gen_range(1..=1000);
+                let known = riddle.known(c, 2);
+                (format!("{known} - {unknown}"), c - value)
+            }
+            3 => {
+                let c = riddle.rng.gen_range(1..=1000);
+                let known = riddle.known(c, 2);
+                (format!("{unknown} - {known}"), value - c)
+            }
+            _ => {
+                let c = riddle.rng.gen_range(1..=1000);
+                let known = riddle.known(c, 2);
+                (format!("{unknown} + {known}"), value + c)
+            }
+        };
+
+        riddle.job(&name, job);
+        (unknown, value) = (name, next);
+    }
+
+    let other = riddle.known(value, 3);
+    let job = match riddle.rng.gen_bool(0.5) {
+        true => format!("{unknown} + {other}"),
+        false => format!("{other} + {unknown}"),
+    };
+    riddle.job("root", job);
+
+    let mut jobs = riddle.jobs;
+    jobs.shuffle(rng);
+    jobs.concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solve::Task;
+    use common::Solution;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<Task>(input, 40, 0..10).unwrap();
+    }
+
+    #[test]
+    fn answers() {
+        for seed in 0..10 {
+            let mut rng = common::generate::rng(seed);
+            let answer = rng.clone().gen_range(1..100_000);
+            let task = Task::parse(&input(&mut rng, 40)).unwrap();
+            assert_eq!(task.part2(), answer);
+        }
+    }
+}
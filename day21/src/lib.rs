@@ -1,16 +1,17 @@
-use color_eyre::{eyre::eyre, Result};
+use color_eyre::Result;
+use common::parse::{context, finish, IResult};
 use core::panic;
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{alpha1, multispace1},
-    combinator::{all_consuming, map},
+    character::complete::{alpha1, char, multispace1},
+    combinator::{all_consuming, cut, map},
     multi::separated_list1,
-    sequence::{separated_pair, tuple},
-    Finish, IResult,
+    sequence::{pair, separated_pair, tuple},
 };
 use std::collections::HashMap;
 
+pub mod generate;
 pub mod solve;
 
 pub const EXAMPLE: &str = "\
@@ -33,16 +34,16 @@ hmdt: 32";
 pub type Int = i64;
 
 #[derive(Debug, Clone)]
-pub enum Step<'s> {
+pub enum Step {
     Shout(Int),
-    Add(&'s str, &'s str),
-    Mul(&'s str, &'s str),
-    Sub(&'s str, &'s str),
-    Div(&'s str, &'s str),
+    Add(String, String),
+    Mul(String, String),
+    Sub(String, String),
+    Div(String, String),
 }
 
 #[derive(Debug)]
-pub struct Input<'s>(HashMap<&'s str, Step<'s>>);
+pub struct Input(HashMap<String, Step>);
 
 fn parse_expression(i: &str) -> IResult<&str, Step> {
     alt((
@@ -53,30 +54,35 @@ fn parse_expression(i: &str) -> IResult<&str, Step> {
                 alt((tag(" + "), tag(" - "), tag(" * "), tag(" / "))),
                 alpha1,
             )),
-            |(lhs, op, rhs)| match op {
-                " + " => Step::Add(lhs, rhs),
-                " - " => Step::Sub(lhs, rhs),
-                " * " => Step::Mul(lhs, rhs),
-                " / " => Step::Div(lhs, rhs),
-                _ => panic!("bad operator: {op}"),
+            |(lhs, op, rhs): (&str, &str, &str)| {
+                let (lhs, rhs) = (lhs.to_owned(), rhs.to_owned());
+                match op {
+                    " + " => Step::Add(lhs, rhs),
+                    " - " => Step::Sub(lhs, rhs),
+                    " * " => Step::Mul(lhs, rhs),
+                    " / " => Step::Div(lhs, rhs),
+                    _ => panic!("bad operator: {op}"),
+                }
             },
         ),
     ))(i)
 }
 
 fn parse_step(i: &str) -> IResult<&str, (&str, Step)> {
-    separated_pair(alpha1, tag(": "), parse_expression)(i)
+    separated_pair(
+        alpha1,
+        cut(pair(char(':'), char(' '))),
+        cut(context("a number or an operation", parse_expression)),
+    )(i)
 }
 
-pub fn parse_input(i: &'static str) -> Result<Input<'static>> {
-    let (s, steps) = all_consuming(separated_list1(multispace1, parse_step))(i.trim())
-        .finish()
-        .or(Err(eyre!("failed to parse input")))?;
-    assert!(s.is_empty());
+pub fn parse_input(i: &str) -> Result<Input> {
+    let steps = all_consuming(separated_list1(multispace1, parse_step))(i.trim());
+    let steps = finish(i, steps)?;
 
     let map = steps
         .into_iter()
-        .map(|(n, s)| (n, s))
+        .map(|(n, s)| (n.to_owned(), s))
         .collect::<HashMap<_, _>>();
 
     Ok(Input(map))
@@ -95,4 +101,19 @@ mod tests {
         assert!(matches!(input.0.get("drzm"), Some(Step::Sub(_, _))));
         assert!(matches!(input.0.get("hmdt"), Some(Step::Shout(_))));
     }
+
+    #[test]
+    fn parse_errors() {
+        let error = |input: &str| {
+            let err = parse_input(input).unwrap_err();
+            let err = err.downcast_ref::<common::parse::ParseError>().unwrap();
+            (err.line, err.column, err.expected.clone())
+        };
+
+        let input = EXAMPLE.replace("drzm * dbpl", "drzm x dbpl");
+        assert_eq!(error(&input), (10, 11, "a number or an operation".into()));
+
+        let input = EXAMPLE.replace("humn: 5", "humn 5");
+        assert_eq!(error(&input), (8, 5, "':'".into()));
+    }
 }
@@ -1,6 +1,7 @@
 // Following https://github.com/Crazytieguy/advent-of-code/blob/master/2022/src/bin/day21/main.rs
 use crate::{parse_input, Input, Int, Step};
 use color_eyre::Result;
+use common::Solution;
 use std::collections::HashMap;
 
 const HUMAN: &str = "humn";
@@ -64,10 +65,10 @@ impl<'s> Expression<'s> {
 struct Expressions<'s>(HashMap<&'s str, Expression<'s>>);
 
 impl<'s> Expressions<'s> {
-    fn from(steps: &'s HashMap<&'s str, Step>) -> Self {
+    fn from(steps: &'s HashMap<String, Step>) -> Self {
         let statements = steps
             .iter()
-            .map(|(&name, step)| (name, Expression::from(step)))
+            .map(|(name, step)| (name.as_str(), Expression::from(step)))
             .collect::<HashMap<_, _>>();
         Self(statements)
     }
@@ -92,11 +93,11 @@ impl<'s> Expressions<'s> {
     }
 }
 
-pub struct Task<'s> {
-    pub input: Input<'s>,
+pub struct Task {
+    pub input: Input,
 }
 
-impl<'s> Task<'s> {
+impl Task {
     pub fn part1(&self) -> Int {
         let mut cache = HashMap::<String, Int>::new();
 
@@ -153,11 +154,28 @@ impl<'s> Task<'s> {
     }
 }
 
-pub fn parse(s: &'static str) -> Result<Task> {
+pub fn parse(s: &str) -> Result<Task> {
     let input = parse_input(s)?;
     Ok(Task { input })
 }
 
+impl Solution for Task {
+    type Part1 = Int;
+    type Part2 = Int;
+
+    fn parse(input: &str) -> Result<Self> {
+        parse(input)
+    }
+
+    fn part1(&self) -> Result<Int> {
+        Ok(self.part1())
+    }
+
+    fn part2(&self) -> Result<Int> {
+        Ok(self.part2())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
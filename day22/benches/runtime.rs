@@ -0,0 +1 @@
+common::benchmarks!("day22", include_str!("../data/input.txt"), day22::Task);
@@ -0,0 +1,110 @@
+// Boards that fold into a cube `size` tiles along each side, with a path across them.  The board is
+// one of the eleven nets of a cube, turned or flipped over at random, with a wall on about one tile
+// in ten.  The tile the path starts from is always open.
+use common::generate::{Rng, SliceRandom, StdRng};
+
+// The faces of each net, by row and column
+const NETS: [[(usize, usize); 6]; 11] = [
+    [(0, 0), (1, 0), (1, 1), (1, 2), (1, 3), (2, 0)],
+    [(0, 0), (1, 0), (1, 1), (1, 2), (1, 3), (2, 1)],
+    [(0, 0), (1, 0), (1, 1), (1, 2), (1, 3), (2, 2)],
+    [(0, 0), (1, 0), (1, 1), (1, 2), (1, 3), (2, 3)],
+    [(0, 1), (1, 0), (1, 1), (1, 2), (1, 3), (2, 1)],
+    [(0, 1), (1, 0), (1, 1), (1, 2), (1, 3), (2, 2)],
+    [(0, 0), (0, 1), (1, 1), (1, 2), (1, 3), (2, 1)],
+    [(0, 0), (0, 1), (1, 1), (1, 2), (1, 3), (2, 2)],
+    [(0, 0), (0, 1), (1, 1), (1, 2), (1, 3), (2, 3)],
+    [(0, 0), (0, 1), (1, 1), (1, 2), (2, 2), (2, 3)],
+    [(0, 0), (0, 1), (0, 2), (1, 2), (1, 3), (1, 4)],
+];
+
+// One of the eight ways of turning a net and flipping it over
+fn orient(net: &[(usize, usize)], orientation: usize) -> Vec<(usize, usize)> {
+    let faces = net
+        .iter()
+        .map(|&(row, col)| {
+            let (mut row, mut col) = (row as isize, col as isize);
+            for _ in 0..orientation % 4 {
+                (row, col) = (col, -row);
+            }
+            if orientation >= 4 {
+                col = -col;
+            }
+            (row, col)
+        })
+        .collect::<Vec<_>>();
+
+    let top = faces.iter().map(|&(row, _)| row).min().unwrap();
+    let left = faces.iter().map(|&(_, col)| col).min().unwrap();
+    faces
+        .into_iter()
+        .map(|(row, col)| ((row - top) as usize, (col - left) as usize))
+        .collect()
+}
+
+fn board(rng: &mut StdRng, faces: &[(usize, usize)], side: usize) -> String {
+    let rows = faces.iter().map(|&(row, _)| row).max().unwrap() + 1;
+    let mut start = true;
+
+    let lines = (0..rows * side).map(|row| {
+        let cols = faces
+            .iter()
+            .filter(|&&(r, _)| r == row / side)
+            .map(|&(_, c)| c)
+            .max()
+            .unwrap();
+
+        (0..(cols + 1) * side)
+            .map(|col| match faces.contains(&(row / side, col / side)) {
+                false => ' ',
+                true if std::mem::take(&mut start) => '.',
+                true if rng.gen_bool(0.1) => '#',
+                true => '.',
+            })
+            .collect::<String>()
+    });
+
+    lines.map(|line| line + "\n").collect()
+}
+
+fn path(rng: &mut StdRng, side: usize) -> String {
+    (0..4 * side)
+        .map(|i| {
+            let turn = match i {
+                0 => "",
+                _ => ["L", "R"].choose(rng).unwrap(),
+            };
+            format!("{turn}{}", rng.gen_range(1..=2 * side))
+        })
+        .collect()
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let side = size.max(2);
+    let faces = orient(NETS.choose(rng).unwrap(), rng.gen_range(0..8));
+    format!("{}\n{}\n", board(rng, &faces, side), path(rng, side))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Task;
+    use itertools::Itertools;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<Task>(input, 10, 0..10).unwrap();
+    }
+
+    #[test]
+    fn every_net() {
+        let mut rng = common::generate::rng(0);
+        for (net, orientation) in NETS.iter().cartesian_product(0..8) {
+            let faces = orient(net, orientation);
+            let input = format!("{}\n{}\n", board(&mut rng, &faces, 4), path(&mut rng, 4));
+            let task = Task::parse(&input).unwrap();
+            task.part1();
+            task.part2();
+        }
+    }
+}
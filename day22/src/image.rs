@@ -0,0 +1,71 @@
+// The board of the second part with the path walked around the cube drawn over it, each square of
+// the trail colored by the direction last faced on it, as in the puzzle's own drawing of >, v, <
+// and ^.
+use crate::{parse, Cube, Move, Nothing, Pos, Tile, Wall};
+use color_eyre::Result;
+use common::image::{Image, Rgb};
+use geometry::Dir;
+use std::collections::HashMap;
+
+const NOTHING: Rgb = [15, 15, 20];
+const TILE: Rgb = [200, 200, 190];
+const WALL: Rgb = [70, 60, 60];
+const RIGHT: Rgb = [220, 70, 60];
+const DOWN: Rgb = [60, 170, 80];
+const LEFT: Rgb = [60, 110, 220];
+const UP: Rgb = [230, 180, 40];
+
+pub fn draw(input: &str) -> Result<Image> {
+    let notes = parse(input)?;
+    let path = notes.path.clone();
+    let mut cube = Cube::new(notes);
+    let mut trail = HashMap::from([(cube.pos, cube.dxy)]);
+
+    for mv in path {
+        let steps = match mv {
+            Move::Forward(n) => n,
+            Move::Rotate(_) => 1,
+        };
+        let mv = match mv {
+            Move::Forward(_) => Move::Forward(1),
+            turn => turn,
+        };
+
+        for _ in 0..steps {
+            cube.step(mv);
+            trail.insert(cube.pos, cube.dxy);
+        }
+    }
+
+    let map = &cube.notes.map;
+    Ok(Image::from_fn(map.width(), map.height(), |x, y| {
+        let pos = Pos::new(x as i32, y as i32);
+        match (trail.get(&pos), cube.notes.val(pos)) {
+            (Some(Dir::Right), _) => RIGHT,
+            (Some(Dir::Down), _) => DOWN,
+            (Some(Dir::Left), _) => LEFT,
+            (Some(Dir::Up), _) => UP,
+            (None, Some(Tile)) => TILE,
+            (None, Some(Wall)) => WALL,
+            (None, Some(Nothing) | None) => NOTHING,
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let image = draw(include_str!("../data/example.txt")).unwrap();
+
+        assert_eq!((image.width(), image.height()), (16, 12));
+        assert_eq!(image.get(0, 0), Some(NOTHING));
+        assert_eq!(image.get(11, 0), Some(WALL));
+        // The walk starts on the left of the top face, facing right, and ends facing up at row 5,
+        // column 7 (counting from 1)
+        assert_eq!(image.get(8, 0), Some(RIGHT));
+        assert_eq!(image.get(6, 4), Some(UP));
+    }
+}
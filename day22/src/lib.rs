@@ -0,0 +1,666 @@
+// Reference solutions for part 2:
+// - https://github.com/mfornet/advent-of-code-2022/blob/main/day22b/src/main.rs
+//   Revisit.  Generic in dimensions.  Generic in shape.  Uses division by 6 and the square root of
+//   the number of cells to find the length of a side.  Inneficient iteration over path commands.
+// - https://github.com/idanarye/aoc-2022/blob/main/src/day22.rs
+//   Revisit.  Ranges for row and column indexes.  Regex for parsing commands.  Index trait.
+//   Generic in dimensions.  .
+// - https://github.com/jchevertonwynne/advent-of-code-2022/blob/main/src/days/day22.rs
+//   Use of a hash map with a fast hasher for the world map, not inserting anything into the map
+//   for the blank regions.  Generic in dimensions (?).  Hard-coded in arrangement of faces to one
+//   another (?).
+// - https://github.com/Crazytieguy/advent-of-code/blob/master/2022/src/bin/day22/main.rs
+//   Use of Rotation(Clockwise).  Use of Turn(Rotation).  Hard-coded in dimensions.  Hard coded in
+//   shape.
+// - https://github.com/sanraith/aoc2022/blob/aa33a4a7a8dfe6e522a5fe6af39c17b35892e465/aoc-lib/src/solutions/year2022/day22.rs
+//   Has a context utility for printing out progress.  Generic solution.
+// - https://github.com/HoshigaIkaro/aoc-2022/blob/main/src/days/day_22.rs
+//   Use of integer assignments in enum.  Use of a template parameter to differentiate a "Flat"
+//   implementation from a "Cube" implementation.  Hard-coded dimensions.
+// - https://github.com/kelleyvanevert/adventofcode2022/blob/main/day22/src/main.rs
+//   quaternion::{axis_angle, rotate_vector}, itertools::tuple_combinations.  Generic solution.
+// - https://github.com/pavel1269/advent-of-code/blob/main/2022/src/day22/mod.rs
+//   test_case to create permutations of a generic test
+// - https://gist.github.com/mgedmin/71d632e40d4de5c9486a4616ffb53208
+//   bit vectors to compute shared edges
+//
+use color_eyre::{eyre::eyre, Result};
+use common::Solution;
+use geometry::{Dir, Turn, Vec2};
+use grid::Grid;
+use regex::Regex;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+};
+
+pub mod generate;
+pub mod image;
+
+type Int = i32;
+
+// x is the column and y is the row of a position on the board
+type Pos = Vec2<Int>;
+
+fn relative(pos: Pos, side: Int) -> Pos {
+    Pos::new(pos.x.rem_euclid(side), pos.y.rem_euclid(side))
+}
+
+//    00 01 02 03 04 05 06 07 08 09 10 11 12 13 14 15
+// 00                         .  .  .  #
+// 01                         .  #  .  .
+// 02                         #  .  .  .
+// 03                         .  .  .  .
+// 04 .  .  .  #  .  .  E  .  .  .  .  #
+// 05 .  .  .  .  .  .  .  .  #  .  .  A
+// 06 .  .  #  .  .  .  .  #  .  .  .  .
+// 07 .  D  .  .  .  .  .  .  .  .  #  .
+// 08                         .  .  .  #  .  .  B  .
+// 09                         .  .  .  .  .  #  .  .
+// 10                         .  #  .  .  .  .  .  .
+// 11                         .  .  C  .  .  .  #  .
+//
+// - At A, and move to the right -> B, facing down
+//
+//   top left corner: (8, 12)
+//   (4, 11)  -> (8, 15)  [4, 4]
+//   {0, 3}      {0, 3}   [0, side-i-1]
+//
+//   (7, 11)  -> (8, 12)  [1, 1]
+//   {3, 3}      {0, 0}   [0, side-i-1]
+//
+// The coordinates above are given as (row, column).
+//
+// Crossing an edge keeps the distance from the edge's end on the left hand side, whatever the way
+// in and the way out of it are.
+fn rotate(pos: Pos, old: Dir, new: Dir, side: Int) -> Pos {
+    let Vec2 { x, y } = pos;
+    let last = side - 1;
+
+    let from_left = match old {
+        Dir::Right => y,
+        Dir::Down => last - x,
+        Dir::Left => last - y,
+        Dir::Up => x,
+    };
+
+    match new {
+        Dir::Right => Pos::new(0, from_left),
+        Dir::Down => Pos::new(last - from_left, 0),
+        Dir::Left => Pos::new(last, last - from_left),
+        Dir::Up => Pos::new(from_left, last),
+    }
+}
+
+// The order in which the neighboring faces are looked for when stitching together the cube
+const DIRECTIONS: [Dir; 4] = [Dir::Down, Dir::Right, Dir::Up, Dir::Left];
+
+// The value of a direction in the password
+fn facing(dir: Dir) -> Int {
+    match dir {
+        Dir::Right => 0,
+        Dir::Down => 1,
+        Dir::Left => 2,
+        Dir::Up => 3,
+    }
+}
+
+// Faces are numbered by their column and row on the board, starting from 1
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+struct Face(Pos);
+
+impl Face {
+    fn of(pos: Pos, side: Int) -> Self {
+        Self(Pos::new((pos.x + side) / side, (pos.y + side) / side))
+    }
+
+    #[allow(unused)]
+    fn new(x: Int, y: Int) -> Self {
+        Self(Pos::new(x, y))
+    }
+
+    fn top_left_corner(&self, side: Int) -> Pos {
+        Pos::new((self.0.x - 1) * side, (self.0.y - 1) * side)
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone)]
+enum Square {
+    Tile,
+    Wall,
+    Nothing,
+}
+use Square::*;
+
+impl Debug for Square {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Tile => write!(f, "."),
+            Self::Wall => write!(f, "#"),
+            Self::Nothing => write!(f, " "),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+enum Move {
+    Forward(Int),
+    Rotate(Turn),
+}
+use Move::*;
+
+#[derive(Clone, Debug)]
+struct Notes {
+    map: Grid<Square>,
+    path: Vec<Move>,
+    side: Int,
+    transitions: HashMap<(Face, Dir), (Face, Dir)>,
+}
+
+impl Notes {
+    fn starting_position(&self) -> Pos {
+        let j = self
+            .map
+            .row(0)
+            .iter()
+            .position(|&square| square == Tile)
+            .unwrap();
+
+        Pos::new(j as Int, 0)
+    }
+
+    fn val(&self, pos: Pos) -> Option<&Square> {
+        self.map.get(grid::Pos::new(
+            pos.y.try_into().ok()?,
+            pos.x.try_into().ok()?,
+        ))
+    }
+}
+
+fn parse(s: &str) -> Result<Notes> {
+    let (map, commands) = s
+        .split_once("\n\n")
+        .ok_or_else(|| eyre!("missing the path after the map"))?;
+
+    let map = Grid::parse_padded(map, Nothing, |c| match c {
+        ' ' => Ok(Nothing),
+        '.' => Ok(Tile),
+        '#' => Ok(Wall),
+        _ => Err(eyre!("unknown square: {}", c)),
+    })?;
+
+    let pattern = Regex::new(r"\d+|[LR]").unwrap();
+    let path = pattern
+        .captures_iter(commands)
+        .flat_map(|cap| {
+            if let Some(s) = cap.get(0) {
+                match s.as_str() {
+                    "L" => Some(Rotate(Turn::Left)),
+                    "R" => Some(Rotate(Turn::Right)),
+                    n => Some(Forward(n.parse::<Int>().expect("an integer"))),
+                }
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let count = map.iter().filter(|(_, s)| matches!(s, Tile | Wall)).count();
+
+    if count % 6 != 0 {
+        return Err(eyre!("bad dimensions"));
+    }
+
+    let side = ((count / 6) as f32).sqrt() as Int;
+    if count as Int != 6 * side * side {
+        return Err(eyre!("bad dimensions"));
+    }
+
+    let mut transitions = HashMap::new();
+    let mut faces = HashSet::new();
+
+    for (pos, cell) in map.iter() {
+        if cell == &Square::Nothing {
+            continue;
+        }
+        let face = Face::of(Pos::new(pos.col as Int, pos.row as Int), side);
+        faces.insert(face);
+    }
+
+    assert_eq!(faces.len(), 6);
+    let mut missing = 6 * 4;
+
+    for &face in &faces {
+        for dxy in DIRECTIONS {
+            let next_face = Face(face.0 + dxy.into());
+
+            if let Some(&next_face) = faces.get(&next_face) {
+                transitions.entry((face, dxy)).or_insert((next_face, dxy));
+                missing -= 1;
+            }
+        }
+    }
+
+    while missing > 0 {
+        for &face in &faces {
+            for dxy in DIRECTIONS {
+                if transitions.get(&(face, dxy)).is_some() {
+                    continue;
+                }
+
+                let move_left = dxy.turn_left();
+
+                if let Some(&(next_face, next_dxy)) = transitions.get(&(face, move_left)) {
+                    let move_right = next_dxy.turn_right();
+
+                    if let Some(&(next_face, next_dxy)) = transitions.get(&(next_face, move_right))
+                    {
+                        let move_left = next_dxy.turn_left();
+                        transitions
+                            .entry((face, dxy))
+                            .or_insert((next_face, move_left));
+                        missing -= 1;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(Notes {
+        map,
+        side,
+        path,
+        transitions,
+    })
+}
+
+trait State {
+    fn advance(&mut self) -> &mut Self;
+    fn position(&self) -> (Pos, Dir);
+
+    fn password(&self) -> Int {
+        let (pos, dxy) = self.position();
+        (pos.y + 1) * 1000 + (pos.x + 1) * 4 + facing(dxy)
+    }
+}
+
+struct Flat {
+    pos: Pos,
+    dxy: Dir,
+    notes: Notes,
+}
+
+impl State for Flat {
+    fn position(&self) -> (Pos, Dir) {
+        let Self { pos, dxy, .. } = self;
+        (*pos, *dxy)
+    }
+
+    fn advance(&mut self) -> &mut Self {
+        let mut moves = self.notes.path.iter().rev().copied().collect::<Vec<_>>();
+
+        while let Some(mv) = moves.pop() {
+            self.step(mv);
+        }
+
+        self
+    }
+}
+
+impl Flat {
+    fn new(notes: Notes) -> Self {
+        Self {
+            pos: notes.starting_position(),
+            dxy: Dir::Right,
+            notes,
+        }
+    }
+
+    fn wrap(&self, pos: Pos) -> Pos {
+        let x = pos.x.rem_euclid(self.notes.map.width() as Int);
+        let y = pos.y.rem_euclid(self.notes.map.height() as Int);
+
+        Pos::new(x, y)
+    }
+
+    fn attempt_move(&self, pos: Pos, dxy: Dir) -> (bool, Pos) {
+        let mut next_pos = self.wrap(pos + dxy.into());
+
+        while next_pos != pos {
+            match self.notes.val(next_pos) {
+                Some(Tile) => return (true, next_pos),
+                Some(Wall) => return (false, pos),
+                Some(Nothing) | None => {
+                    next_pos = self.wrap(next_pos + dxy.into());
+                }
+            }
+        }
+
+        // We should not reach this point unless there's something wrong with the map or our code.
+        unreachable!()
+    }
+
+    fn step(&mut self, mv: Move) {
+        match mv {
+            Rotate(turn) => self.dxy = self.dxy.turn(turn),
+
+            Forward(mut n) => {
+                while n > 0 {
+                    let (moved, next_pos) = self.attempt_move(self.pos, self.dxy);
+
+                    if !moved {
+                        break;
+                    }
+
+                    self.pos = next_pos;
+                    n -= 1;
+                }
+            }
+        };
+    }
+}
+
+struct Cube {
+    pos: Pos,
+    dxy: Dir,
+    notes: Notes,
+}
+
+impl State for Cube {
+    fn position(&self) -> (Pos, Dir) {
+        (self.pos, self.dxy)
+    }
+
+    fn advance(&mut self) -> &mut Self {
+        let mut moves = self.notes.path.iter().rev().copied().collect::<Vec<_>>();
+
+        while let Some(mv) = moves.pop() {
+            self.step(mv);
+        }
+
+        self
+    }
+}
+
+impl Cube {
+    fn new(notes: Notes) -> Self {
+        Self {
+            pos: notes.starting_position(),
+            dxy: Dir::Right,
+            notes,
+        }
+    }
+
+    #[allow(unused)]
+    fn face(&self) -> Face {
+        Face::of(self.pos, self.notes.side)
+    }
+
+    fn step(&mut self, mv: Move) {
+        match mv {
+            Rotate(turn) => self.dxy = self.dxy.turn(turn),
+
+            Forward(mut n) => {
+                while n > 0 {
+                    let (moved, next_pos, next_dxy) = self.attempt_move(self.pos, self.dxy, 0);
+                    n -= 1;
+
+                    if !moved {
+                        break;
+                    }
+
+                    self.dxy = next_dxy;
+                    self.pos = next_pos;
+                }
+            }
+        };
+    }
+
+    fn attempt_move(&self, pos: Pos, dxy: Dir, depth: i8) -> (bool, Pos, Dir) {
+        let side = self.notes.side;
+        let next_pos = pos + dxy.into();
+
+        match self.notes.val(next_pos) {
+            Some(Tile) => (true, next_pos, dxy),
+            Some(Wall) => (false, pos, dxy),
+
+            Some(Nothing) | None => {
+                if depth > 1 {
+                    // If we reach this point, we did not stitch together the faces of the cube
+                    // correctly from the puzzle input, or the puzzle input is malformed.
+                    unreachable!();
+                }
+
+                let face = Face::of(pos, side);
+                let &(next_face, next_dxy) = self
+                    .notes
+                    .transitions
+                    .get(&(face, dxy))
+                    .expect("a transition");
+
+                let corner = next_face.top_left_corner(side);
+                let next_pos = corner + rotate(relative(pos, side), dxy, next_dxy, side);
+                self.attempt_move(next_pos - next_dxy.into(), next_dxy, depth + 1)
+            }
+        }
+    }
+}
+
+pub struct Task {
+    notes: Notes,
+}
+
+impl Task {
+    pub fn parse(s: &str) -> Result<Self> {
+        let notes = parse(s)?;
+        Ok(Self { notes })
+    }
+
+    pub fn part1(&self) -> Int {
+        Flat::new(self.notes.clone()).advance().password()
+    }
+
+    pub fn part2(&self) -> Int {
+        Cube::new(self.notes.clone()).advance().password()
+    }
+}
+
+impl Solution for Task {
+    type Part1 = Int;
+    type Part2 = Int;
+
+    fn parse(input: &str) -> Result<Self> {
+        Self::parse(input)
+    }
+
+    fn part1(&self) -> Result<Int> {
+        Ok(self.part1())
+    }
+
+    fn part2(&self) -> Result<Int> {
+        Ok(self.part2())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn parsing() {
+        let input = include_str!("../data/example.txt");
+        let notes = parse(input).unwrap();
+
+        assert_eq!(notes.side, 4);
+
+        assert_eq!(notes.map.width(), 16);
+        assert_eq!(
+            notes.path,
+            &[
+                Move::Forward(10),
+                Move::Rotate(Turn::Right),
+                Move::Forward(5),
+                Move::Rotate(Turn::Left),
+                Move::Forward(5),
+                Move::Rotate(Turn::Right),
+                Move::Forward(10),
+                Move::Rotate(Turn::Left),
+                Move::Forward(4),
+                Move::Rotate(Turn::Right),
+                Move::Forward(5),
+                Move::Rotate(Turn::Left),
+                Move::Forward(5)
+            ]
+        );
+    }
+
+    #[test]
+    fn corners() {
+        let input = include_str!("../data/example.txt");
+        let notes = parse(input).unwrap();
+        let side = notes.side;
+
+        let faces = notes
+            .transitions
+            .keys()
+            .map(|(face, _)| face)
+            .collect::<HashSet<_>>();
+
+        let corners = faces
+            .iter()
+            .map(|face| face.top_left_corner(side))
+            .sorted_by_key(|corner| (corner.y, corner.x))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            &corners,
+            &[
+                Pos::new(8, 0),
+                Pos::new(0, 4),
+                Pos::new(4, 4),
+                Pos::new(8, 4),
+                Pos::new(8, 8),
+                Pos::new(12, 8)
+            ]
+        );
+    }
+
+    #[test]
+    fn part1() {
+        let input = include_str!("../data/example.txt");
+        let notes = parse(input).unwrap();
+        let task = Task { notes };
+        assert_eq!(task.part1(), 6032);
+    }
+
+    #[test]
+    fn part2() {
+        let input = include_str!("../data/example.txt");
+        let notes = parse(input).unwrap();
+        let mut moves = notes.path.iter().rev().copied().collect::<Vec<_>>();
+        let mut state = Cube::new(notes);
+
+        state.step(moves.pop().unwrap());
+        assert_eq!(state.face(), Face::new(3, 1));
+        assert_eq!(state.pos, Pos::new(10, 0));
+        assert_eq!(state.dxy, Dir::Right);
+        assert_eq!(state.password(), 1044);
+
+        state.step(moves.pop().unwrap());
+        assert_eq!(state.face(), Face::new(3, 1));
+        assert_eq!(state.pos, Pos::new(10, 0));
+        assert_eq!(state.dxy, Dir::Down);
+        assert_eq!(state.password(), 1045);
+
+        state.step(moves.pop().unwrap());
+        assert_eq!(state.face(), Face::new(3, 2));
+        assert_eq!(state.pos, Pos::new(10, 5));
+        assert_eq!(state.dxy, Dir::Down);
+        assert_eq!(state.password(), 6045);
+
+        state.step(moves.pop().unwrap());
+        assert_eq!(state.face(), Face::new(3, 2));
+        assert_eq!(state.pos, Pos::new(10, 5));
+        assert_eq!(state.dxy, Dir::Right);
+        assert_eq!(state.password(), 6044);
+
+        state.step(moves.pop().unwrap());
+        assert_eq!(state.face(), Face::new(4, 3));
+        assert_eq!(state.pos, Pos::new(14, 10));
+        assert_eq!(state.dxy, Dir::Down);
+        assert_eq!(state.password(), 11061);
+
+        state.step(moves.pop().unwrap());
+        assert_eq!(state.face(), Face::new(4, 3));
+        assert_eq!(state.pos, Pos::new(14, 10));
+        assert_eq!(state.dxy, Dir::Left);
+        assert_eq!(state.password(), 11062);
+
+        state.step(moves.pop().unwrap());
+        assert_eq!(state.face(), Face::new(3, 3));
+        assert_eq!(state.pos, Pos::new(10, 10));
+        assert_eq!(state.dxy, Dir::Left);
+        assert_eq!(state.password(), 11046);
+
+        state.step(moves.pop().unwrap());
+        assert_eq!(state.face(), Face::new(3, 3));
+        assert_eq!(state.pos, Pos::new(10, 10));
+        assert_eq!(state.dxy, Dir::Down);
+        assert_eq!(state.password(), 11045);
+
+        state.step(moves.pop().unwrap());
+        assert_eq!(state.face(), Face::new(1, 2));
+        assert_eq!(state.pos, Pos::new(1, 5));
+        assert_eq!(state.dxy, Dir::Up);
+        assert_eq!(state.password(), 6011);
+
+        state.step(moves.pop().unwrap());
+        assert_eq!(state.face(), Face::new(1, 2));
+        assert_eq!(state.pos, Pos::new(1, 5));
+        assert_eq!(state.dxy, Dir::Right);
+        assert_eq!(state.password(), 6008);
+
+        state.step(moves.pop().unwrap());
+        assert_eq!(state.face(), Face::new(2, 2));
+        assert_eq!(state.pos, Pos::new(6, 5));
+        assert_eq!(state.dxy, Dir::Right);
+        assert_eq!(state.password(), 6028);
+
+        state.step(moves.pop().unwrap());
+        assert_eq!(state.face(), Face::new(2, 2));
+        assert_eq!(state.pos, Pos::new(6, 5));
+        assert_eq!(state.dxy, Dir::Up);
+        assert_eq!(state.password(), 6031);
+
+        state.step(moves.pop().unwrap());
+        assert_eq!(state.face(), Face::new(2, 2));
+        assert_eq!(state.pos, Pos::new(6, 4));
+        assert_eq!(state.dxy, Dir::Up);
+        assert_eq!(state.password(), 5031);
+
+        assert!(moves.is_empty());
+    }
+
+    #[test]
+    fn input() {
+        let input = include_str!("../data/input.txt");
+        let notes = parse(input).unwrap();
+
+        assert_eq!(notes.side, 50);
+
+        let path = notes.path.clone();
+        let n = path.len();
+        assert_eq!(path[0], Move::Forward(47));
+        assert_eq!(path[n - 2], Move::Rotate(Turn::Left));
+        assert_eq!(path[n - 1], Move::Forward(37));
+
+        let task = Task { notes };
+        assert_eq!(task.part1(), 1428);
+
+        let p2 = task.part2();
+        assert!(p2 > 41381);
+        assert!(p2 > 124158);
+        assert_eq!(p2, 142380);
+    }
+}
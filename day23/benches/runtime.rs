@@ -0,0 +1 @@
+common::benchmarks!("day23", include_str!("../data/input.txt"), day23::Task);
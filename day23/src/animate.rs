@@ -0,0 +1,67 @@
+// The elves spreading out a round at a time, until none of them moves.  The frame covers everywhere
+// the elves have been, and so only ever grows.
+use crate::{parse, Pos, State};
+use color_eyre::Result;
+use common::animate::Animation;
+use geometry::Bounds;
+
+struct Rounds {
+    state: State,
+    bounds: Bounds<Pos>,
+}
+
+impl Animation for Rounds {
+    fn step(&mut self) -> bool {
+        if !self.state.moved {
+            return false;
+        }
+
+        self.state = self.state.clone().step();
+        let bounds = self.state.grid.bounds();
+        self.bounds.include(bounds.min);
+        self.bounds.include(bounds.max);
+
+        true
+    }
+
+    fn frame(&self) -> String {
+        let Bounds { min, max } = self.bounds;
+
+        (min.y..=max.y)
+            .flat_map(|i| {
+                (min.x..=max.x)
+                    .map(move |j| match self.state.grid.has_elf(i, j) {
+                        true => '#',
+                        false => '.',
+                    })
+                    .chain(['\n'])
+            })
+            .collect()
+    }
+}
+
+pub fn animation(input: &str) -> Result<Box<dyn Animation>> {
+    let state = parse(input)?.start();
+    let bounds = state.grid.bounds();
+
+    Ok(Box::new(Rounds { state, bounds }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_example() {
+        let mut rounds = animation(".....\n..##.\n..#..\n.....\n..##.\n.....\n").unwrap();
+        assert_eq!(rounds.frame(), "##\n#.\n..\n##\n");
+
+        let mut steps = 0;
+        while rounds.step() {
+            steps += 1;
+        }
+
+        assert_eq!(steps, 4);
+        assert_eq!(rounds.frame(), "..#..\n....#\n#....\n....#\n.....\n..#..\n");
+    }
+}
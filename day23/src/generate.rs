@@ -0,0 +1,37 @@
+// Scans of a square grove `size` tiles across, up to 72, with an elf on about half of the tiles
+// and always one in the middle, so that there's an elf to follow however small the scan.  The
+// elves are laid out in a fixed grid with room for them to spread out from a scan as big as the
+// puzzle's own, and no more.
+use common::generate::{Rng, StdRng};
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let side = size.clamp(1, 72);
+
+    (0..side)
+        .map(|row| {
+            let row = (0..side)
+                .map(|col| {
+                    let middle = (row, col) == (side / 2, side / 2);
+                    if middle || rng.gen_bool(0.5) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>();
+            row + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        for size in [1, 2, 3, 10, 20] {
+            common::generate::check::<crate::Task>(input, size, 0..10).unwrap();
+        }
+    }
+}
@@ -0,0 +1,60 @@
+// Where the elves end up once none of them moves any more, over the ground they started out on,
+// which is tinted so that the spreading out shows.
+use crate::{parse, Pos};
+use color_eyre::Result;
+use common::image::{Image, Rgb};
+use geometry::Bounds;
+
+const GROUND: Rgb = [30, 60, 30];
+const START: Rgb = [90, 120, 60];
+const ELF: Rgb = [240, 240, 230];
+
+pub fn draw(input: &str) -> Result<Image> {
+    let task = parse(input)?;
+    let start = &task.grid;
+    let end = task.advance(100_000).grid;
+
+    let mut bounds: Bounds<Pos> = start.bounds();
+    bounds.include(end.bounds().min);
+    bounds.include(end.bounds().max);
+    let Bounds { min, max } = bounds;
+
+    Ok(Image::from_fn(
+        max.x - min.x + 1,
+        max.y - min.y + 1,
+        |x, y| {
+            let (i, j) = (min.y + y, min.x + x);
+            if end.has_elf(i, j) {
+                ELF
+            } else if start.has_elf(i, j) {
+                START
+            } else {
+                GROUND
+            }
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_example() {
+        let image = draw(".....\n..##.\n..#..\n.....\n..##.\n.....\n").unwrap();
+
+        assert_eq!((image.width(), image.height()), (5, 6));
+        let rows = (0..6)
+            .map(|y| {
+                (0..5)
+                    .map(|x| match image.get(x, y).unwrap() {
+                        ELF => '#',
+                        START => 'o',
+                        _ => '.',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(rows, ["..#..", "..oo#", "#.o..", "....#", "..oo.", "..#.."]);
+    }
+}
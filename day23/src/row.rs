@@ -0,0 +1,203 @@
+// A row of the grid, with a bit for each column: column j is bit j % 8 of lane j / 8 of a SIMD vector
+// on nightly, and bit j % 64 of word j / 64 of an array otherwise.  Shifting a row east or west
+// carries bits from one lane or word into the next, and wraps around at the ends of the row, the
+// same either way, which the tests check on nightly by comparing the two.
+#[cfg(feature = "nightly")]
+pub use simd::{Row, BITS_PER_ROW};
+#[cfg(not(feature = "nightly"))]
+pub use words::{Row, BITS_PER_ROW};
+
+#[cfg(feature = "nightly")]
+mod simd {
+    use auto_ops::impl_op_ex;
+    use std::ops::{BitAnd, BitAndAssign, BitOrAssign};
+    use std::simd;
+
+    // Since the elves expand out from their initial position, you need a wide enough row to
+    // accomodate the expansion.  In the case of the inputs provided, u8x16 is not wide enough.
+    type SimdVec = simd::u8x32;
+    pub const BITS_PER_ROW: usize = 8 * SimdVec::LANES; // 256
+
+    #[derive(Clone, Copy, Default)]
+    pub struct Row(SimdVec);
+
+    impl_op_ex!(!|a: &Row| -> Row { Row(!a.0) });
+    impl_op_ex!(| |a: &Row, b: &Row | -> Row { Row(a.0 | b.0) });
+
+    impl BitAnd for Row {
+        type Output = Self;
+
+        fn bitand(self, rhs: Self) -> Self::Output {
+            Self(self.0 & rhs.0)
+        }
+    }
+
+    impl BitAndAssign for Row {
+        fn bitand_assign(&mut self, rhs: Self) {
+            self.0 &= rhs.0
+        }
+    }
+
+    impl BitOrAssign for Row {
+        fn bitor_assign(&mut self, rhs: Self) {
+            self.0 |= rhs.0
+        }
+    }
+
+    impl Row {
+        pub fn shift_west(&self) -> Self {
+            Self(
+                (self.0 >> SimdVec::splat(1))
+                    | (self.0.rotate_lanes_left::<1>() << SimdVec::splat(7)),
+            )
+        }
+
+        pub fn shift_east(&self) -> Self {
+            Self(
+                (self.0 << SimdVec::splat(1))
+                    | (self.0.rotate_lanes_right::<1>() >> SimdVec::splat(7)),
+            )
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.0 == SimdVec::splat(0)
+        }
+
+        pub fn count_ones(&self) -> usize {
+            self.0
+                .as_array()
+                .iter()
+                .map(|x| x.count_ones() as usize)
+                .sum()
+        }
+
+        // j / 8, to get the index of the lane for j, since there are 8 bits per lane
+        pub fn insert(&mut self, j: usize) {
+            self.0[j / 8] |= 1 << (j % 8);
+        }
+
+        pub fn contains(&self, j: usize) -> bool {
+            self.0[j / 8] & (1 << (j % 8)) != 0
+        }
+    }
+}
+
+#[cfg(any(not(feature = "nightly"), test))]
+mod words {
+    use auto_ops::impl_op_ex;
+    use std::array;
+
+    const WORDS: usize = 4;
+    pub const BITS_PER_ROW: usize = 64 * WORDS; // 256
+
+    #[derive(Clone, Copy, Default)]
+    pub struct Row([u64; WORDS]);
+
+    impl_op_ex!(!|a: &Row| -> Row { Row(a.0.map(|w| !w)) });
+    impl_op_ex!(| |a: &Row, b: &Row | -> Row { Row(array::from_fn(|k| a.0[k] | b.0[k])) });
+    impl_op_ex!(&|a: &Row, b: &Row| -> Row { Row(array::from_fn(|k| a.0[k] & b.0[k])) });
+    impl_op_ex!(&= |a: &mut Row, b: &Row| { *a = *a & b });
+    impl_op_ex!(|= |a: &mut Row, b: &Row| { *a = *a | b });
+
+    impl Row {
+        pub fn shift_west(&self) -> Self {
+            Self(array::from_fn(|k| {
+                (self.0[k] >> 1) | (self.0[(k + 1) % WORDS] << 63)
+            }))
+        }
+
+        pub fn shift_east(&self) -> Self {
+            Self(array::from_fn(|k| {
+                (self.0[k] << 1) | (self.0[(k + WORDS - 1) % WORDS] >> 63)
+            }))
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.0 == [0; WORDS]
+        }
+
+        pub fn count_ones(&self) -> usize {
+            self.0.iter().map(|x| x.count_ones() as usize).sum()
+        }
+
+        // j / 64, to get the index of the word for j, since there are 64 bits per word
+        pub fn insert(&mut self, j: usize) {
+            self.0[j / 64] |= 1 << (j % 64);
+        }
+
+        pub fn contains(&self, j: usize) -> bool {
+            self.0[j / 64] & (1 << (j % 64)) != 0
+        }
+    }
+}
+
+#[cfg(all(test, feature = "nightly"))]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // The columns that are set in a row of either kind
+    macro_rules! columns {
+        ($row:expr) => {
+            (0..BITS_PER_ROW)
+                .filter(|&j| $row.contains(j))
+                .collect::<Vec<_>>()
+        };
+    }
+
+    fn columns() -> impl Strategy<Value = Vec<usize>> {
+        prop::collection::vec(0..BITS_PER_ROW, 0..=64)
+    }
+
+    #[test]
+    fn same_width() {
+        assert_eq!(simd::BITS_PER_ROW, words::BITS_PER_ROW);
+    }
+
+    proptest! {
+        #[test]
+        fn same_as_words(a in columns(), b in columns()) {
+            let (mut simd_a, mut simd_b) = (simd::Row::default(), simd::Row::default());
+            let (mut words_a, mut words_b) = (words::Row::default(), words::Row::default());
+            for &j in &a {
+                simd_a.insert(j);
+                words_a.insert(j);
+            }
+            for &j in &b {
+                simd_b.insert(j);
+                words_b.insert(j);
+            }
+
+            // Each operation, done the same way on both kinds of row
+            macro_rules! same {
+                (|$a:ident, $b:ident| $op:expr) => {{
+                    let simd = {
+                        let ($a, $b) = (simd_a, simd_b);
+                        $op
+                    };
+                    let words = {
+                        let ($a, $b) = (words_a, words_b);
+                        $op
+                    };
+                    prop_assert_eq!(columns!(simd), columns!(words));
+                    prop_assert_eq!(simd.count_ones(), words.count_ones());
+                    prop_assert_eq!(simd.is_empty(), words.is_empty());
+                }};
+            }
+
+            same!(|a, _b| a);
+            same!(|a, _b| a.shift_west());
+            same!(|a, _b| a.shift_east());
+            same!(|a, _b| a.shift_west().shift_west().shift_east());
+            same!(|a, _b| !a);
+            same!(|a, b| a | b);
+            same!(|a, b| a & b);
+            same!(|a, b| {
+                let mut a = a;
+                a &= !b;
+                a |= b.shift_east();
+                a
+            });
+        }
+    }
+}
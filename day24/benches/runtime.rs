@@ -0,0 +1 @@
+common::benchmarks!("day24", include_str!("../data/input.txt"), day24::Task);
@@ -0,0 +1,90 @@
+// The expedition making its way through the blizzards to the exit, a minute at a time.  Every place
+// the expedition could have got to by then is drawn as E, over the blizzards as they're drawn by
+// the state's Debug impl.
+use crate::{parse, Destination, State};
+use color_eyre::Result;
+use common::animate::Animation;
+
+struct Expedition {
+    state: State,
+    positions: Vec<u128>,
+    arrived: bool,
+}
+
+impl Animation for Expedition {
+    fn step(&mut self) -> bool {
+        if self.arrived {
+            return false;
+        }
+
+        self.positions = self.state.advance(&self.positions, Destination::Exit);
+        self.arrived = self
+            .state
+            .reached_destination(&self.positions, Destination::Exit);
+
+        true
+    }
+
+    fn frame(&self) -> String {
+        let width = self.state.width;
+        let wall = |gap: usize, arrived: bool| -> String {
+            (0..width + 2)
+                .map(|j| match j == gap {
+                    true if arrived => 'E',
+                    true => '.',
+                    false => '#',
+                })
+                .chain(['\n'])
+                .collect()
+        };
+
+        let valley = format!("{:?}", self.state)
+            .lines()
+            .zip(&self.positions)
+            .map(|(line, &row)| {
+                let cells = line.chars().enumerate().map(|(j, c)| match row >> j & 1 {
+                    1 => 'E',
+                    _ => c,
+                });
+                ['#']
+                    .into_iter()
+                    .chain(cells)
+                    .chain(['#', '\n'])
+                    .collect::<String>()
+            })
+            .collect::<String>();
+
+        let in_valley = self.positions.iter().any(|&row| row != 0);
+        wall(1, !in_valley) + &valley + &wall(width, self.arrived)
+    }
+}
+
+pub fn animation(input: &str) -> Result<Box<dyn Animation>> {
+    let state = parse(input)?.initial_state;
+
+    Ok(Box::new(Expedition {
+        positions: vec![0; state.height],
+        state,
+        arrived: false,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let mut expedition = animation(include_str!("../data/example.txt")).unwrap();
+        assert_eq!(expedition.frame(), include_str!("../data/example.txt"));
+
+        let mut steps = 0;
+        while expedition.step() {
+            steps += 1;
+        }
+
+        // The solution counts the minute it takes to step out of the valley as well
+        assert_eq!(steps + 1, 18);
+        assert!(expedition.frame().lines().last().unwrap().contains('E'));
+    }
+}
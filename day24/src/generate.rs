@@ -0,0 +1,40 @@
+// Valleys `size` tiles wide, up to 120 so that a row fits in a u128, and a quarter as high, with a
+// blizzard on about a third of the tiles.  As in the puzzle, no blizzard blows up or down the
+// columns of the entrance and the exit, where it would blow out of the valley.
+use common::generate::{Rng, SliceRandom, StdRng};
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let width = size.clamp(3, 120);
+    let height = (size / 4).max(3);
+
+    let wall = |gap: usize| {
+        let wall = (0..width + 2).map(|col| if col == gap { '.' } else { '#' });
+        wall.collect::<String>() + "\n"
+    };
+
+    let rows = (0..height).map(|_| {
+        let row = (0..width).map(|col| {
+            let blizzards: &[char] = match col {
+                col if col == 0 || col == width - 1 => &['<', '>'],
+                _ => &['<', '>', '^', 'v'],
+            };
+            match rng.gen_bool(0.35) {
+                true => *blizzards.choose(rng).unwrap(),
+                false => '.',
+            }
+        });
+        format!("#{}#\n", row.collect::<String>())
+    });
+
+    wall(1) + &rows.collect::<String>() + &wall(width)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<crate::Task>(input, 40, 0..10).unwrap();
+    }
+}
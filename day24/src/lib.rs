@@ -0,0 +1,316 @@
+// https://adventofcode.com/2022/day/24
+//
+// Solutions from https://www.reddit.com/r/adventofcode/comments/zu28ij/2022_day_24_solutions/
+//  - https://www.reddit.com/r/adventofcode/comments/zu28ij/comment/j32ncvf/ (210ms)
+//
+use color_eyre::{eyre::eyre, Result};
+use common::Solution;
+use grid::Grid;
+use itertools::izip;
+use std::{collections::VecDeque, fmt::Debug};
+
+pub mod animate;
+pub mod generate;
+
+// The blizzards going each way, a bit for each cell rather than a grid::Grid, so that a whole row
+// of them moves with a single shift
+#[derive(Clone)]
+struct State {
+    down: VecDeque<u128>,
+    height: usize,
+    left: Vec<u128>,
+    right: Vec<u128>,
+    up: VecDeque<u128>,
+    width: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Destination {
+    Exit,
+    Entrance,
+}
+
+use Destination::*;
+
+// Draws the blizzards in the valley, showing how many there are where more than one overlap
+impl Debug for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut grid = Grid::new(self.width, self.height, '.');
+
+        for (pos, cell) in grid.iter_mut() {
+            let bit = 1 << pos.col;
+            let winds = [
+                ('^', self.up[pos.row]),
+                ('v', self.down[pos.row]),
+                ('<', self.left[pos.row]),
+                ('>', self.right[pos.row]),
+            ]
+            .into_iter()
+            .filter(|(_, row)| row & bit != 0)
+            .map(|(c, _)| c)
+            .collect::<Vec<_>>();
+
+            *cell = match winds[..] {
+                [] => '.',
+                [c] => c,
+                _ => char::from_digit(winds.len() as u32, 10).unwrap_or('*'),
+            };
+        }
+
+        f.write_str(&grid.render(|&c| c))
+    }
+}
+
+fn mask(width: usize) -> u128 {
+    (1 << width) - 1
+}
+
+impl State {
+    fn tick(&mut self) {
+        self.up.rotate_left(1);
+        self.down.rotate_right(1);
+
+        self.left.iter_mut().for_each(|row| {
+            *row = (*row >> 1) | ((*row & 1) << (self.width - 1));
+        });
+        self.right.iter_mut().for_each(|row| {
+            // Q: Why are we shifting left to represent winds moving to the right?
+            // A: Because we're treating (0, 0) as 1 (no left shifting) at row 0.
+            *row = (*row << 1) | (*row >> (self.width - 1));
+            // Q: Why do we need the mask here, but not in the previous case?
+            // A: Perhaps because the map is left-justified.
+            *row &= mask(self.width);
+        });
+    }
+
+    fn travel_to(&mut self, dest: Destination) -> usize {
+        assert_eq!(self.height, self.right.len());
+        let mut positions = vec![0; self.height];
+
+        for minute in 1.. {
+            positions = self.advance(&positions, dest);
+
+            if self.reached_destination(&positions, dest) {
+                self.tick();
+                return minute + 1;
+            }
+        }
+
+        unreachable!()
+    }
+
+    // Moves the blizzards on a minute, and works out where the expedition could be by then
+    fn advance(&mut self, positions: &[u128], dest: Destination) -> Vec<u128> {
+        self.tick();
+        let mut positions = self.possible_moves(positions);
+
+        // Add an elf at the beginning or end of the maze to start the walk again in case other
+        // possible moves are eliminated during the round.  This move will also be checked against
+        // the wind patterns and may itself be eliminated during the round.
+        match dest {
+            Exit => positions[0] |= 1,
+            Entrance => positions[self.height - 1] |= 1 << (self.width - 1),
+        }
+
+        let it = izip!(
+            &mut positions,
+            &self.up,
+            &self.down,
+            &self.left,
+            &self.right
+        );
+
+        // Eliminate possible moves that are incomptabile with the wind patterns.
+        for (row, up, down, left, right) in it {
+            *row &= !(up | down | left | right);
+        }
+
+        positions
+    }
+
+    fn reached_destination(&self, positions: &[u128], dest: Destination) -> bool {
+        matches!(dest, Exit) && positions[self.height - 1] >> (self.width - 1) == 1
+            || matches!(dest, Entrance) && positions[0] & 1 == 1
+    }
+
+    fn possible_moves(&self, positions: &[u128]) -> Vec<u128> {
+        let mut new_positions = vec![0; self.height];
+
+        let it = izip!(
+            &mut new_positions,
+            [0].iter().chain(positions),
+            positions,
+            positions.iter().skip(1).chain([0].iter())
+        );
+
+        for (row, above, cur, bellow) in it {
+            *row = (cur | cur << 1 | cur >> 1 | above | bellow) & mask(self.width);
+        }
+
+        new_positions
+    }
+}
+
+pub struct Task {
+    initial_state: State,
+}
+
+impl Task {
+    pub fn part1(&self) -> usize {
+        let mut state = self.initial_state.clone();
+        state.travel_to(Exit)
+    }
+
+    pub fn part2(&self) -> usize {
+        let mut state = self.initial_state.clone();
+        let mut minutes = state.travel_to(Exit);
+        minutes += state.travel_to(Entrance);
+        minutes += state.travel_to(Exit);
+        minutes
+    }
+}
+
+pub fn parse(s: &str) -> Result<Task> {
+    let lines = s.lines().collect::<Vec<_>>();
+    let first = lines
+        .first()
+        .ok_or_else(|| eyre!("the map is empty"))?
+        .len();
+    if first < 3 {
+        return Err(eyre!("the map has to be at least 3 wide, not {first}"));
+    }
+    if let Some((i, line)) = lines
+        .iter()
+        .enumerate()
+        .find(|(_, line)| line.len() != first)
+    {
+        return Err(eyre!(
+            "line {} is {} wide rather than {first}",
+            i + 1,
+            line.len()
+        ));
+    }
+
+    // A bit for each column of the basin, with one to spare for the mask of them all
+    let width = first - 2;
+    if width >= u128::BITS as usize {
+        return Err(eyre!("the basin is {width} wide, and can be at most 127"));
+    }
+
+    let (up, (down, (left, right))): (VecDeque<_>, (VecDeque<_>, (Vec<_>, Vec<_>))) = lines
+        .iter()
+        .filter(|line| line.as_bytes()[2] != b'#')
+        .map(|line| {
+            let (mut up, mut down, mut left, mut right) = (0, 0, 0, 0);
+
+            line.bytes()
+                .filter(|&c| c != b'#')
+                .enumerate()
+                .for_each(|(j, c)| {
+                    let bit = 1 << j;
+                    match c {
+                        b'^' => up |= bit,
+                        b'>' => right |= bit,
+                        b'v' => down |= bit,
+                        b'<' => left |= bit,
+                        _ => {}
+                    };
+                });
+
+            (up, (down, (left, right)))
+        })
+        .unzip();
+
+    if up.is_empty() {
+        return Err(eyre!("there's no basin between the walls"));
+    }
+
+    let initial_state = State {
+        height: up.len(),
+        up,
+        down,
+        left,
+        right,
+        width,
+    };
+
+    Ok(Task { initial_state })
+}
+
+impl Solution for Task {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        parse(input)
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(self.part1())
+    }
+
+    fn part2(&self) -> Result<usize> {
+        Ok(self.part2())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bad_maps() {
+        assert!(parse("").is_err());
+        assert!(parse("#.\n.#\n").is_err());
+        assert!(parse("#.#\n#.#\n").is_err());
+        assert!(parse("#.###\n#..#\n###.#\n").is_err());
+
+        let wide = |width| {
+            let wall = "#".repeat(width);
+            format!("#.{wall}\n#{}#\n{wall}.#\n", ".".repeat(width))
+        };
+        assert_eq!(parse(&wide(127)).unwrap().part1(), 128);
+        assert!(parse(&wide(128)).is_err());
+    }
+
+    #[test]
+    fn parsing() {
+        let input = include_str!("../data/example.txt");
+        let task = parse(input).unwrap();
+        assert_eq!(task.initial_state.height, 4);
+        assert_eq!(task.initial_state.width, 6);
+
+        let mut state = task.initial_state;
+        assert_eq!(format!("{state:?}"), ">>.<^<\n.<..<<\n>v.><>\n<^v^^>\n");
+
+        state.tick();
+        assert_eq!(format!("{state:?}"), ".>3.<.\n<..<<.\n>2.22.\n>v..^<\n");
+    }
+
+    #[test]
+    fn part1() {
+        let input = include_str!("../data/example.txt");
+        let task = parse(input).unwrap();
+        assert_eq!(task.part1(), 18);
+    }
+
+    #[test]
+    fn part2() {
+        let input = include_str!("../data/example.txt");
+        let task = parse(input).unwrap();
+        assert_eq!(task.part2(), 54);
+    }
+
+    #[test]
+    fn input() {
+        let input = include_str!("../data/input.txt");
+        let task = parse(input).unwrap();
+
+        let part1 = task.part1();
+        assert_eq!(part1, 266);
+
+        let part2 = task.part2();
+        assert!(part2 > 805);
+        assert_eq!(part2, 853);
+    }
+}
@@ -0,0 +1 @@
+common::benchmarks!("day25", include_str!("../data/input.txt"), day25::Task);
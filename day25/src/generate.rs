@@ -0,0 +1,23 @@
+// Lists of `size` fuel requirements, from one to fifteen SNAFU digits long like the puzzle's.
+use crate::Snafu;
+use common::generate::{Rng, StdRng};
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let digits = rng.gen_range(1..=15);
+            let fuel = rng.gen_range(5i64.pow(digits - 1)..5i64.pow(digits));
+            format!("{}\n", Snafu(fuel))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves() {
+        common::generate::check::<crate::Task>(input, 100, 0..10).unwrap();
+    }
+}
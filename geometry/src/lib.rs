@@ -0,0 +1,380 @@
+// Integer vectors in two and three dimensions, shared by the days that move things around on a
+// plane or in space.
+//
+// In two dimensions, y increases going down, as it does for the rows of a map drawn with
+// characters.  A clockwise turn is then clockwise as seen on the screen: Right turns into Down.
+use num_traits::{PrimInt, Signed};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+macro_rules! impl_ops {
+    ($name:ident { $($field:ident),+ }) => {
+        impl<T: Add<Output = T>> Add for $name<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $name<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $name<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        // Scaling by a number
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $name<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self::Output {
+                Self { $($field: self.$field * rhs),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $name<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$field += rhs.$field;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $name<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$field -= rhs.$field;)+
+            }
+        }
+
+        impl<T: Copy + Ord> Componentwise for $name<T> {
+            fn component_min(self, other: Self) -> Self {
+                Self { $($field: self.$field.min(other.$field)),+ }
+            }
+
+            fn component_max(self, other: Self) -> Self {
+                Self { $($field: self.$field.max(other.$field)),+ }
+            }
+
+            fn all_le(self, other: Self) -> bool {
+                true $(&& self.$field <= other.$field)+
+            }
+        }
+
+        impl<T: PrimInt> $name<T> {
+            pub fn splat(v: T) -> Self {
+                Self { $($field: v),+ }
+            }
+        }
+
+        impl<T: PrimInt + Signed> $name<T> {
+            pub fn abs(self) -> Self {
+                Self { $($field: self.$field.abs()),+ }
+            }
+
+            // Each component replaced by -1, 0 or 1, which is a single step towards the vector
+            pub fn signum(self) -> Self {
+                Self { $($field: self.$field.signum()),+ }
+            }
+
+            pub fn manhattan(self, other: Self) -> T {
+                let d = (self - other).abs();
+                T::zero() $(+ d.$field)+
+            }
+
+            // The number of king moves between two points
+            pub fn chebyshev(self, other: Self) -> T {
+                let d = (self - other).abs();
+                T::zero() $(.max(d.$field))+
+            }
+        }
+    };
+}
+
+impl_ops!(Vec2 { x, y });
+impl_ops!(Vec3 { x, y, z });
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Neg<Output = T>> Vec2<T> {
+    pub fn rotate_cw(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    pub fn rotate_ccw(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    pub fn rotate(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.rotate_ccw(),
+            Turn::Right => self.rotate_cw(),
+        }
+    }
+}
+
+impl<T: PrimInt + Signed> Vec2<T> {
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        Dir::ALL.into_iter().map(move |dir| self + dir.into())
+    }
+
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        let range = || [-T::one(), T::zero(), T::one()];
+        range()
+            .into_iter()
+            .flat_map(move |dy| range().into_iter().map(move |dx| Self::new(dx, dy)))
+            .filter(|d| *d != Self::splat(T::zero()))
+            .map(move |d| self + d)
+    }
+}
+
+impl<T> Vec3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Copy> Vec3<T> {
+    pub fn get(&self, axis: Axis) -> T {
+        match axis {
+            Axis::X => self.x,
+            Axis::Y => self.y,
+            Axis::Z => self.z,
+        }
+    }
+}
+
+impl<T: PrimInt + Signed> Vec3<T> {
+    // The six points sharing a face with this one
+    pub fn neighbors6(self) -> impl Iterator<Item = Self> {
+        let (zero, one) = (T::zero(), T::one());
+        [
+            Self::new(one, zero, zero),
+            Self::new(zero, one, zero),
+            Self::new(zero, zero, one),
+        ]
+        .into_iter()
+        .flat_map(move |d| [self + d, self - d])
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    // In clockwise order
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+impl<T: PrimInt + Signed> From<Dir> for Vec2<T> {
+    fn from(dir: Dir) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        match dir {
+            Dir::Up => Self::new(zero, -one),
+            Dir::Right => Self::new(one, zero),
+            Dir::Down => Self::new(zero, one),
+            Dir::Left => Self::new(-one, zero),
+        }
+    }
+}
+
+// Vectors that can be compared and combined component by component, so that they can be boxed in
+pub trait Componentwise: Copy {
+    fn component_min(self, other: Self) -> Self;
+
+    fn component_max(self, other: Self) -> Self;
+
+    fn all_le(self, other: Self) -> bool;
+}
+
+// The smallest box containing a set of points, including the points on its edges
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Bounds<P> {
+    pub min: P,
+    pub max: P,
+}
+
+impl<P: Componentwise> Bounds<P> {
+    pub fn new(p: P) -> Self {
+        Self { min: p, max: p }
+    }
+
+    pub fn from_points<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = P>,
+    {
+        let mut points = points.into_iter();
+        let mut bounds = Self::new(points.next()?);
+        for p in points {
+            bounds.include(p);
+        }
+        Some(bounds)
+    }
+
+    pub fn include(&mut self, p: P) {
+        self.min = self.min.component_min(p);
+        self.max = self.max.component_max(p);
+    }
+
+    pub fn contains(&self, p: P) -> bool {
+        self.min.all_le(p) && p.all_le(self.max)
+    }
+}
+
+impl<P: Componentwise + Add<Output = P> + Sub<Output = P>> Bounds<P> {
+    // Grows the box by the given margin on every side
+    pub fn expand(self, margin: P) -> Self {
+        Self {
+            min: self.min - margin,
+            max: self.max + margin,
+        }
+    }
+}
+
+impl<T: PrimInt> Bounds<Vec2<T>> {
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::one()
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::one()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Vec2::new(1, -2);
+        let b = Vec2::new(4, 2);
+        assert_eq!(a + b, Vec2::new(5, 0));
+        assert_eq!(b - a, Vec2::new(3, 4));
+        assert_eq!(-a * 3, Vec2::new(-3, 6));
+        assert_eq!((b - a).signum(), Vec2::new(1, 1));
+
+        let mut c = Vec3::new(1, 2, 3);
+        c += Vec3::splat(1);
+        assert_eq!(c, Vec3::new(2, 3, 4));
+        assert_eq!(c.get(Axis::Z), 4);
+    }
+
+    #[test]
+    fn distances() {
+        let a = Vec2::new(1, -2);
+        let b = Vec2::new(4, 2);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+
+        let c = Vec3::new(1i16, 2, 3);
+        assert_eq!(c.manhattan(Vec3::new(2, 2, 2)), 2);
+        assert_eq!(c.chebyshev(Vec3::new(2, 2, 2)), 1);
+    }
+
+    #[test]
+    fn rotations() {
+        let right = Vec2::<i32>::from(Dir::Right);
+        assert_eq!(right.rotate_cw(), Dir::Down.into());
+        assert_eq!(right.rotate_ccw(), Dir::Up.into());
+        assert_eq!(right.rotate(Turn::Left).rotate(Turn::Right), right);
+
+        for dir in Dir::ALL {
+            assert_eq!(
+                Vec2::<i32>::from(dir.turn_right()),
+                Vec2::from(dir).rotate_cw()
+            );
+            assert_eq!(dir.turn(Turn::Left).turn(Turn::Right), dir);
+            assert_eq!(dir.reverse().reverse(), dir);
+        }
+        assert_eq!(Dir::Up.turn_left(), Dir::Left);
+        assert_eq!(Dir::Left.reverse(), Dir::Right);
+    }
+
+    #[test]
+    fn neighbors() {
+        let p = Vec2::new(0, 0);
+        assert_eq!(p.neighbors4().count(), 4);
+        assert_eq!(p.neighbors8().count(), 8);
+        assert!(p.neighbors8().all(|q| p.chebyshev(q) == 1));
+
+        let c = Vec3::new(0, 0, 0);
+        assert!(c.neighbors6().all(|q| c.manhattan(q) == 1));
+        assert_eq!(c.neighbors6().count(), 6);
+    }
+
+    #[test]
+    fn bounds() {
+        let points = [Vec2::new(3, 1), Vec2::new(-1, 4), Vec2::new(2, 2)];
+        let bounds = Bounds::from_points(points).unwrap();
+        assert_eq!(bounds.min, Vec2::new(-1, 1));
+        assert_eq!(bounds.max, Vec2::new(3, 4));
+        assert_eq!((bounds.width(), bounds.height()), (5, 4));
+        assert!(bounds.contains(Vec2::new(0, 3)));
+        assert!(!bounds.contains(Vec2::new(0, 5)));
+
+        let bounds = bounds.expand(Vec2::splat(1));
+        assert!(bounds.contains(Vec2::new(0, 5)));
+        assert_eq!(Bounds::<Vec2<i32>>::from_points([]), None);
+    }
+}
@@ -0,0 +1,426 @@
+// A rectangular grid of cells stored in row-major order, shared by the days whose puzzle input is a
+// map drawn with characters.  Days 23 and 24 are the exception: they keep a bit for each cell in
+// words as wide as a row, so that every elf or blizzard in a row moves with a single shift, and
+// only use a grid to draw the map.
+use color_eyre::{eyre::eyre, Result};
+use std::{
+    fmt::{self, Debug, Write},
+    iter,
+    ops::{Index, IndexMut},
+};
+
+// The position of a cell.  Rows increase going down the map and columns increase going right.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    // The position one step away in the direction of the offset, if it is not off the top or the
+    // left of the map.  Use Grid::step to also check the bottom and right edges.
+    pub fn step(self, offset: Offset) -> Option<Self> {
+        Some(Self {
+            row: self.row.checked_add_signed(offset.drow)?,
+            col: self.col.checked_add_signed(offset.dcol)?,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Offset {
+    pub drow: isize,
+    pub dcol: isize,
+}
+
+impl Offset {
+    pub const UP: Self = Self::new(-1, 0);
+    pub const DOWN: Self = Self::new(1, 0);
+    pub const LEFT: Self = Self::new(0, -1);
+    pub const RIGHT: Self = Self::new(0, 1);
+    pub const UP_LEFT: Self = Self::new(-1, -1);
+    pub const UP_RIGHT: Self = Self::new(-1, 1);
+    pub const DOWN_LEFT: Self = Self::new(1, -1);
+    pub const DOWN_RIGHT: Self = Self::new(1, 1);
+
+    pub const ORTHOGONAL: [Self; 4] = [Self::UP, Self::RIGHT, Self::DOWN, Self::LEFT];
+    pub const ALL: [Self; 8] = [
+        Self::UP_LEFT,
+        Self::UP,
+        Self::UP_RIGHT,
+        Self::LEFT,
+        Self::RIGHT,
+        Self::DOWN_LEFT,
+        Self::DOWN,
+        Self::DOWN_RIGHT,
+    ];
+
+    pub const fn new(drow: isize, dcol: isize) -> Self {
+        Self { drow, dcol }
+    }
+}
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    // Parses a map in which lines can be shorter than the widest line, such as the board of day
+    // 22.  The missing cells at the end of the short lines are filled in.
+    pub fn parse_padded<F>(s: &str, fill: T, mut f: F) -> Result<Self>
+    where
+        F: FnMut(char) -> Result<T>,
+    {
+        let width = s
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let rows = s
+            .lines()
+            .map(|line| {
+                let mut row = line.chars().map(&mut f).collect::<Result<Vec<_>>>()?;
+                row.resize(width, fill.clone());
+                Ok(row)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Self::from_rows(rows)
+    }
+
+    // Adds `count` rows of `fill` in front of row `at`, or below the last row when `at` is the
+    // height, for maps that grow as they go along such as the chamber of day 17
+    pub fn insert_rows(&mut self, at: usize, count: usize, fill: T) {
+        assert!(at <= self.height, "row {at} is past the bottom of the grid");
+        let start = at * self.width;
+        self.cells
+            .splice(start..start, iter::repeat(fill).take(count * self.width));
+        self.height += count;
+    }
+
+    pub fn transpose(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|col| (0..self.height).map(move |row| Pos::new(row, col)))
+            .map(|pos| self[pos].clone())
+            .collect();
+
+        Self {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    pub fn rotate_cw(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|col| (0..self.height).rev().map(move |row| Pos::new(row, col)))
+            .map(|pos| self[pos].clone())
+            .collect();
+
+        Self {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    pub fn rotate_ccw(&self) -> Self {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|col| (0..self.height).map(move |row| Pos::new(row, col)))
+            .map(|pos| self[pos].clone())
+            .collect();
+
+        Self {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_rows<R, I>(rows: R) -> Result<Self>
+    where
+        R: IntoIterator<Item = I>,
+        I: IntoIterator<Item = T>,
+    {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for row in rows {
+            let len = cells.len();
+            cells.extend(row);
+
+            let row_width = cells.len() - len;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(eyre!(
+                        "row {height} has {row_width} cells instead of {width}"
+                    ));
+                }
+                _ => {}
+            }
+
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(eyre!("grid cannot be empty")),
+        }
+    }
+
+    // Parses a map drawn with one character per cell, where every line has the same length
+    pub fn parse<F>(s: &str, mut f: F) -> Result<Self>
+    where
+        F: FnMut(char) -> Result<T>,
+    {
+        let rows = s
+            .lines()
+            .map(|line| line.chars().map(&mut f).collect::<Result<Vec<_>>>())
+            .collect::<Result<Vec<_>>>()?;
+
+        Self::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            self.cells.get(pos.row * self.width + pos.col)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            self.cells.get_mut(pos.row * self.width + pos.col)
+        } else {
+            None
+        }
+    }
+
+    // The position one step away in the direction of the offset, if it is on the map
+    pub fn step(&self, pos: Pos, offset: Offset) -> Option<Pos> {
+        pos.step(offset).filter(|&pos| self.contains(pos))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Pos::new(row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Pos, &mut T)> + '_ {
+        self.positions().zip(&mut self.cells)
+    }
+
+    pub fn position<P>(&self, mut predicate: P) -> Option<Pos>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> + '_ {
+        self.cells.iter().skip(col).step_by(self.width.max(1))
+    }
+
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Offset::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |offset| self.step(pos, offset))
+    }
+
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Offset::ALL
+            .into_iter()
+            .filter_map(move |offset| self.step(pos, offset))
+    }
+
+    // The positions going away from pos in the direction of the offset up to the edge of the map,
+    // not including pos itself.  Rows and columns are walked with the orthogonal offsets and
+    // diagonals with the others.
+    pub fn ray(&self, pos: Pos, offset: Offset) -> impl Iterator<Item = Pos> + '_ {
+        iter::successors(self.step(pos, offset), move |&pos| self.step(pos, offset))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    // Draws the grid with one character per cell and a newline after each row
+    pub fn render<F>(&self, mut f: F) -> String
+    where
+        F: FnMut(&T) -> char,
+    {
+        let mut s = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            s.extend(row.iter().map(&mut f));
+            s.push('\n');
+        }
+        s
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of the grid"))
+    }
+}
+
+// Each cell is expected to be drawn with a single character by its own Debug impl
+impl<T: Debug> Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell:?}")?;
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(s: &str) -> Grid<u32> {
+        Grid::parse(s, |c| {
+            c.to_digit(10).ok_or_else(|| eyre!("not a digit: {c}"))
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn parsing() {
+        let grid = digits("123\n456\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Pos::new(1, 0)], 4);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6]);
+
+        assert!(Grid::<char>::parse("12\n3\n", Ok).is_err());
+        assert!(Grid::<char>::parse("", Ok).is_err());
+        assert!(Grid::parse("1x", |c| c.to_digit(10).ok_or_else(|| eyre!("bad"))).is_err());
+
+        let padded = Grid::parse_padded("  #\n.\n", ' ', Ok).unwrap();
+        assert_eq!(padded.render(|&c| c), "  #\n.  \n");
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = digits("123\n456\n789");
+
+        let corner = grid.neighbors4(Pos::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(corner, vec![Pos::new(0, 1), Pos::new(1, 0)]);
+
+        assert_eq!(grid.neighbors4(Pos::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8(Pos::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbors8(Pos::new(2, 2)).count(), 3);
+        assert_eq!(grid.step(Pos::new(2, 2), Offset::RIGHT), None);
+    }
+
+    #[test]
+    fn rays() {
+        let grid = digits("123\n456\n789");
+        let values = |pos, offset| grid.ray(pos, offset).map(|p| grid[p]).collect::<Vec<_>>();
+
+        assert_eq!(values(Pos::new(1, 0), Offset::RIGHT), vec![5, 6]);
+        assert_eq!(values(Pos::new(2, 1), Offset::UP), vec![5, 2]);
+        assert_eq!(values(Pos::new(0, 0), Offset::DOWN_RIGHT), vec![5, 9]);
+        assert_eq!(values(Pos::new(0, 0), Offset::LEFT), vec![]);
+    }
+
+    #[test]
+    fn transformations() {
+        let grid = digits("123\n456");
+        let render = |grid: &Grid<u32>| grid.render(|&d| char::from_digit(d, 10).unwrap());
+
+        assert_eq!(render(&grid.transpose()), "14\n25\n36\n");
+        assert_eq!(render(&grid.rotate_cw()), "41\n52\n63\n");
+        assert_eq!(render(&grid.rotate_ccw()), "36\n25\n14\n");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(format!("{:?}", grid), "123\n456\n");
+    }
+
+    #[test]
+    fn growing() {
+        let mut grid = digits("123\n456");
+        grid.insert_rows(0, 1, 0);
+        grid.insert_rows(3, 2, 9);
+        grid.insert_rows(2, 1, 7);
+
+        assert_eq!(format!("{:?}", grid), "000\n123\n777\n456\n999\n999\n");
+        assert_eq!(grid.height(), 6);
+        assert_eq!(grid[Pos::new(3, 1)], 5);
+
+        let mut grid = Grid::new(2, 0, 0);
+        grid.insert_rows(0, 1, 1);
+        assert_eq!(format!("{:?}", grid), "11\n");
+    }
+}